@@ -0,0 +1,160 @@
+use std::{error, fmt};
+
+/// 1-based location of a token in the puzzle input. Columns count chars, not
+/// bytes, so carets line up under the token when rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// A parse failure pointing at the offending text in the input.
+///
+/// Parsers build these from the slice they were given, e.g. a single line, and
+/// callers rebase them onto the full input with [`ParseError::within`]. The
+/// [`Display`](fmt::Display) impl renders the offending line with a caret
+/// under the bad token, rustc style.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError<K> {
+    kind: K,
+    offset: usize,
+    text: String,
+    span: Span,
+    line: String,
+}
+
+impl<K> ParseError<K> {
+    /// Error at `token`, which must be a subslice of `source`. An empty slice
+    /// such as `&line[line.len()..]` points just past the end of the line.
+    pub fn new(kind: K, source: &str, token: &str) -> Self {
+        match offset_of(source, token) {
+            Some(offset) => Self::at(kind, source, offset, token.len()),
+            None => Self::at(kind, source, source.len(), 0),
+        }
+    }
+
+    /// Rebases an error produced while parsing `parsed`, a subslice of
+    /// `source`, so that its span is relative to `source`.
+    pub fn within(self, source: &str, parsed: &str) -> Self {
+        match offset_of(source, parsed) {
+            Some(base) => Self::at(self.kind, source, base + self.offset, self.text.len()),
+            None => self,
+        }
+    }
+
+    fn at(kind: K, source: &str, offset: usize, len: usize) -> Self {
+        let line_start = source[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = source[offset..]
+            .find('\n')
+            .map_or(source.len(), |i| offset + i);
+        let span = Span {
+            line: source[..line_start].matches('\n').count() + 1,
+            column: source[line_start..offset].chars().count() + 1,
+        };
+        ParseError {
+            kind,
+            offset,
+            text: source[offset..offset + len].to_owned(),
+            span,
+            line: source[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_owned(),
+        }
+    }
+
+    pub fn kind(&self) -> &K {
+        &self.kind
+    }
+
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// The offending text, empty if the error is about something missing.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+/// Byte offset of `token` within `source`, if it is a subslice of it.
+fn offset_of(source: &str, token: &str) -> Option<usize> {
+    let start = (token.as_ptr() as usize).checked_sub(source.as_ptr() as usize)?;
+    (start + token.len() <= source.len()).then_some(start)
+}
+
+impl<K: fmt::Display> fmt::Display for ParseError<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Span { line, column } = self.span;
+        let gutter = " ".repeat(line.to_string().len());
+        let underline_len = self
+            .text
+            .lines()
+            .next()
+            .map_or(0, |text| text.chars().count())
+            .max(1);
+
+        writeln!(f, "{}", self.kind)?;
+        writeln!(f, "{gutter}--> {line}:{column}")?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{line} | {}", self.line)?;
+        write!(
+            f,
+            "{gutter} | {}{}",
+            " ".repeat(column - 1),
+            "^".repeat(underline_len)
+        )
+    }
+}
+
+impl<K: fmt::Debug + fmt::Display> error::Error for ParseError<K> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct BadNumber;
+
+    impl fmt::Display for BadNumber {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "bad number")
+        }
+    }
+
+    #[test]
+    fn it_locates_tokens_in_later_lines() {
+        let input = "Time: 7 15\nDistance: 9 4x0";
+        let line = input.lines().nth(1).unwrap();
+        let err = ParseError::new(BadNumber, line, &line[12..]).within(input, line);
+        assert_eq!(
+            Span {
+                line: 2,
+                column: 13
+            },
+            err.span()
+        );
+        assert_eq!("4x0", err.text());
+    }
+
+    #[test]
+    fn it_renders_a_caret_under_the_token() {
+        let line = "Distance: 9 4x0";
+        let err = ParseError::new(BadNumber, line, &line[12..]);
+        assert_eq!(
+            "bad number
+ --> 1:13
+  |
+1 | Distance: 9 4x0
+  |             ^^^",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn it_points_past_the_end_for_missing_tokens() {
+        let line = "Card 1";
+        let err = ParseError::new(BadNumber, line, &line[line.len()..]);
+        assert_eq!(Span { line: 1, column: 7 }, err.span());
+        assert!(err.to_string().ends_with("|       ^"));
+    }
+}
@@ -0,0 +1,55 @@
+use std::{error, fmt, io, path::PathBuf};
+
+use crate::{ParseError, Part};
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("failed to read input from {}: {source}", path.display())]
+    Input {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("failed to write {}: {source}", path.display())]
+    Write {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("failed to read input from stdin: {0}")]
+    Stdin(#[source] io::Error),
+
+    #[error("invalid format - {0}")]
+    Format(String),
+
+    #[error("{0}")]
+    Parse(Box<dyn error::Error + Send + Sync>),
+
+    #[error("arithmetic overflow while {0}")]
+    Overflow(&'static str),
+
+    #[error("{0}")]
+    Solve(Box<dyn error::Error + Send + Sync>),
+
+    #[error("day {day} part {part} is not solved yet")]
+    Unsolved { day: u8, part: Part },
+}
+
+impl<K> From<ParseError<K>> for Error
+where
+    K: fmt::Debug + fmt::Display + Send + Sync + 'static,
+{
+    fn from(e: ParseError<K>) -> Self {
+        Error::Parse(Box::new(e))
+    }
+}
+
+impl Error {
+    pub fn format(msg: impl Into<String>) -> Self {
+        Error::Format(msg.into())
+    }
+}
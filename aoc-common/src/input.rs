@@ -0,0 +1,107 @@
+use std::{
+    convert::Infallible,
+    env,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Read},
+    path::PathBuf,
+    str::FromStr,
+};
+
+use crate::{Error, Result};
+
+/// Directory holding `dayNN.txt` puzzle inputs, unless overridden.
+pub const DEFAULT_INPUT_DIR: &str = "inputs";
+
+/// Environment variable overriding [`DEFAULT_INPUT_DIR`].
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// The directory holding puzzle inputs: `AOC_INPUT_DIR` when set, otherwise
+/// [`DEFAULT_INPUT_DIR`].
+pub fn input_dir() -> PathBuf {
+    env::var_os(INPUT_DIR_VAR).map_or_else(|| DEFAULT_INPUT_DIR.into(), PathBuf::from)
+}
+
+/// Where to read a puzzle input from. Parses `-` as stdin and anything else
+/// as a file path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// The conventional input file for `day`, e.g. `inputs/day05.txt`, with the
+    /// directory taken from `AOC_INPUT_DIR` when set.
+    pub fn for_day(day: u8) -> Self {
+        InputSource::File(input_dir().join(format!("day{day:02}.txt")))
+    }
+
+    pub fn read(&self) -> Result<String> {
+        match self {
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(Error::Stdin)?;
+                Ok(input)
+            }
+            InputSource::File(path) => fs::read_to_string(path).map_err(|e| self.error(e)),
+        }
+    }
+
+    /// Opens the input for reading bit by bit, for inputs too large to
+    /// [`read`](InputSource::read) whole.
+    pub fn open(&self) -> Result<Box<dyn BufRead>> {
+        match self {
+            InputSource::Stdin => Ok(Box::new(io::stdin().lock())),
+            InputSource::File(path) => {
+                let file = File::open(path).map_err(|e| self.error(e))?;
+                Ok(Box::new(BufReader::new(file)))
+            }
+        }
+    }
+
+    /// `source` as an error reading this input.
+    pub fn error(&self, source: io::Error) -> Error {
+        match self {
+            InputSource::Stdin => Error::Stdin(source),
+            InputSource::File(path) => Error::Input {
+                path: path.clone(),
+                source,
+            },
+        }
+    }
+}
+
+impl FromStr for InputSource {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "-" => InputSource::Stdin,
+            _ => InputSource::File(s.into()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_names_the_missing_path() {
+        let err = InputSource::File("does/not/exist.txt".into())
+            .read()
+            .unwrap_err();
+        assert!(err.to_string().contains("does/not/exist.txt"));
+    }
+
+    #[test]
+    fn it_parses_dash_as_stdin() {
+        assert_eq!(Ok(InputSource::Stdin), "-".parse());
+        assert_eq!(
+            Ok(InputSource::File("inputs/day05.txt".into())),
+            "inputs/day05.txt".parse()
+        );
+    }
+}
@@ -0,0 +1,13 @@
+//! Shared plumbing for the daily puzzle solutions: input loading, error types
+//! and the small parsing helpers most days need.
+
+pub mod diagnostic;
+pub mod error;
+pub mod input;
+pub mod parse;
+pub mod solution;
+
+pub use diagnostic::{ParseError, Span};
+pub use error::{Error, Result};
+pub use input::{input_dir, InputSource};
+pub use solution::{DynSolution, Part, Solution, Solver};
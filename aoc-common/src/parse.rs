@@ -0,0 +1,59 @@
+use std::str::FromStr;
+
+use crate::ParseError;
+
+/// Returns the part of `line` after the first `':'`, e.g. the numbers in
+/// `"Time:      7  15   30"`.
+pub fn after_colon(line: &str) -> Option<&str> {
+    line.split_once(':').map(|(_, rest)| rest)
+}
+
+/// Parses every whitespace separated token in `s`. On failure, returns the
+/// offending token along with its parse error.
+pub fn numbers<T: FromStr>(s: &str) -> Result<Vec<T>, (&str, T::Err)> {
+    s.split_whitespace()
+        .map(|n| n.parse::<T>().map_err(|e| (n, e)))
+        .collect()
+}
+
+/// Splits `s` into blocks separated by blank lines.
+pub fn blocks(s: &str) -> impl Iterator<Item = &str> {
+    s.split("\n\n")
+}
+
+/// Parses each line of `input`, with errors rebased onto `input` so that they
+/// point at the right line.
+pub fn each_line<T, K>(input: &str) -> Result<Vec<T>, ParseError<K>>
+where
+    T: FromStr<Err = ParseError<K>>,
+{
+    input
+        .lines()
+        .map(|line| {
+            line.parse()
+                .map_err(|e: ParseError<K>| e.within(input, line))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_numbers_after_colon() {
+        let nums = numbers::<usize>(after_colon("Time:      7  15   30").unwrap()).unwrap();
+        assert_eq!(vec![7, 15, 30], nums);
+    }
+
+    #[test]
+    fn it_rejects_lines_without_colon() {
+        assert!(after_colon("Time 7 15 30").is_none());
+    }
+
+    #[test]
+    fn it_returns_the_token_that_failed() {
+        let (token, _) = numbers::<u8>("7 15 300").unwrap_err();
+        assert_eq!("300", token);
+    }
+}
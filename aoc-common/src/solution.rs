@@ -0,0 +1,165 @@
+use std::{fmt, marker::PhantomData, str::FromStr};
+
+use crate::{Error, Result};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl From<Part> for u8 {
+    fn from(part: Part) -> u8 {
+        match part {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", u8::from(*self))
+    }
+}
+
+impl FromStr for Part {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(Error::format(format!("part must be 1 or 2, got {s:?}"))),
+        }
+    }
+}
+
+/// A day's puzzle, split into a parse stage and one solver per part.
+///
+/// `parse` runs once per input and both parts share its output, so tooling can
+/// run and time each stage on its own.
+pub trait Solution {
+    const DAY: u8;
+    /// The parts with a solver, all unless `part2` is left to its default.
+    const PARTS: &'static [Part] = &Part::ALL;
+
+    type Input;
+    type Answer: fmt::Display;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer>;
+
+    fn part2(_input: &Self::Input) -> Result<Self::Answer> {
+        Err(Error::Unsolved {
+            day: Self::DAY,
+            part: Part::Two,
+        })
+    }
+}
+
+/// Object safe view of a [`Solution`], so that every day can live in one list.
+pub trait DynSolution: Sync {
+    fn day(&self) -> u8;
+
+    fn parts(&self) -> &'static [Part];
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Parsed>>;
+}
+
+/// Parsed input of a [`DynSolution`], ready to be solved.
+pub trait Parsed {
+    fn solve(&self, part: Part) -> Result<String>;
+}
+
+pub struct Solver<S>(PhantomData<fn() -> S>);
+
+impl<S> Solver<S> {
+    pub const fn new() -> Self {
+        Solver(PhantomData)
+    }
+}
+
+impl<S> Default for Solver<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> DynSolution for Solver<S>
+where
+    S: Solution + 'static,
+{
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn parts(&self) -> &'static [Part] {
+        S::PARTS
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Parsed>> {
+        let _span = tracing::info_span!("parse", day = S::DAY).entered();
+        Ok(Box::new(ParsedInput::<S>(S::parse(input)?)))
+    }
+}
+
+struct ParsedInput<S: Solution>(S::Input);
+
+impl<S: Solution> Parsed for ParsedInput<S> {
+    fn solve(&self, part: Part) -> Result<String> {
+        match part {
+            Part::One => {
+                let _span = tracing::info_span!("part1", day = S::DAY).entered();
+                S::part1(&self.0).map(|a| a.to_string())
+            }
+            Part::Two => {
+                let _span = tracing::info_span!("part2", day = S::DAY).entered();
+                S::part2(&self.0).map(|a| a.to_string())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+        const PARTS: &'static [Part] = &[Part::One];
+
+        type Input = Vec<u32>;
+        type Answer = u32;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            crate::parse::numbers(input).map_err(|(n, _)| Error::format(n))
+        }
+
+        fn part1(input: &Self::Input) -> Result<Self::Answer> {
+            Ok(input.iter().sum())
+        }
+    }
+
+    #[test]
+    fn it_parses_once_and_solves_each_part() {
+        let solver = Solver::<Sum>::new();
+        assert_eq!([Part::One], solver.parts());
+        let parsed = solver.parse("1 2 3").unwrap();
+        assert_eq!("6", parsed.solve(Part::One).unwrap());
+        assert!(matches!(
+            parsed.solve(Part::Two),
+            Err(Error::Unsolved {
+                day: 0,
+                part: Part::Two
+            })
+        ));
+    }
+}
@@ -0,0 +1,57 @@
+#!/bin/sh
+# Records or checks the reference timings committed in aoc/benches/baseline.
+#
+#   aoc/benches/baseline.sh compare [FILTER]   # fail if any benchmark regressed
+#   aoc/benches/baseline.sh save                # re-record after a change
+#
+# Timings only compare on the machine they were recorded on, so re-record the
+# baseline (and commit it) when benchmarking somewhere new. NOISE_THRESHOLD
+# sets how large a significant change has to be to count, 10% by default.
+set -eu
+
+root=$(cd "$(dirname "$0")/../.." && pwd)
+baseline="$root/aoc/benches/baseline"
+criterion="${CARGO_TARGET_DIR:-$root/target}/criterion"
+threshold="${NOISE_THRESHOLD:-0.10}"
+
+bench() {
+    cargo bench --manifest-path "$root/Cargo.toml" -p aoc --bench days -- "$@"
+}
+
+# Copies the `reference` baseline of every day's benchmarks from $1 to $2.
+copy() {
+    (cd "$1" && find day* -path '*/reference/*.json') | while read -r file; do
+        mkdir -p "$2/$(dirname "$file")"
+        cp "$1/$file" "$2/$file"
+    done
+}
+
+command=${1:-compare}
+[ $# -gt 0 ] && shift
+case "$command" in
+save)
+    bench --save-baseline reference
+    rm -rf "$baseline"
+    copy "$criterion" "$baseline"
+    ;;
+compare)
+    copy "$baseline" "$criterion"
+    log=$(mktemp)
+    trap 'rm -f "$log" "$log.failed"' EXIT
+    {
+        bench --baseline reference --noise-threshold "$threshold" "$@" ||
+            touch "$log.failed"
+    } | tee "$log"
+    if [ -e "$log.failed" ]; then
+        exit 1
+    fi
+    if grep -q "Performance has regressed" "$log"; then
+        echo "error: benchmarks regressed against aoc/benches/baseline" >&2
+        exit 1
+    fi
+    ;;
+*)
+    echo "usage: $0 [compare [FILTER]|save]" >&2
+    exit 2
+    ;;
+esac
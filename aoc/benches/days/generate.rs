@@ -0,0 +1,206 @@
+//! Generators for puzzle inputs well beyond the size of the real ones.
+//!
+//! Every generator is seeded, so the same input is benchmarked on every run
+//! and results can be compared against a saved baseline.
+
+use std::fmt::Write;
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+const SEED: u64 = 2023;
+
+fn rng() -> StdRng {
+    StdRng::seed_from_u64(SEED)
+}
+
+pub fn day01(lines: usize) -> String {
+    const WORDS: &[&str] = &[
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+    let mut rng = rng();
+    let mut s = String::new();
+    for _ in 0..lines {
+        for _ in 0..rng.gen_range(2..8) {
+            match rng.gen_range(0..3) {
+                0 => s.push(char::from(rng.gen_range(b'1'..=b'9'))),
+                1 => s.push_str(WORDS.choose(&mut rng).unwrap()),
+                _ => s.push(char::from(rng.gen_range(b'a'..=b'z'))),
+            }
+        }
+        s.push('\n');
+    }
+    s
+}
+
+pub fn day02(games: usize) -> String {
+    const COLOURS: &[&str] = &["red", "green", "blue"];
+    let mut rng = rng();
+    let mut s = String::new();
+    for id in 1..=games {
+        let reveals = (0..rng.gen_range(1..7))
+            .map(|_| {
+                let mut colours = COLOURS.to_vec();
+                colours.shuffle(&mut rng);
+                colours.truncate(rng.gen_range(1..=3));
+                colours
+                    .iter()
+                    .map(|colour| format!("{} {colour}", rng.gen_range(1..=20)))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect::<Vec<_>>();
+        writeln!(s, "Game {id}: {}", reveals.join("; ")).unwrap();
+    }
+    s
+}
+
+pub fn day03(size: usize) -> String {
+    const SYMBOLS: &[u8] = b"*#+$/=@%&-";
+    let mut rng = rng();
+    let mut s = String::new();
+    for _ in 0..size {
+        let mut line = vec![b'.'; size];
+        let mut x = rng.gen_range(0..4);
+        while x < size {
+            if rng.gen_bool(0.2) {
+                line[x] = *SYMBOLS.choose(&mut rng).unwrap();
+                x += 1;
+            } else {
+                let len = rng.gen_range(1..=3).min(size - x);
+                for c in &mut line[x..x + len] {
+                    *c = rng.gen_range(b'0'..=b'9');
+                }
+                x += len;
+            }
+            x += rng.gen_range(1..6);
+        }
+        s.push_str(std::str::from_utf8(&line).unwrap());
+        s.push('\n');
+    }
+    s
+}
+
+pub fn day04(cards: usize) -> String {
+    let mut rng = rng();
+    let mut numbers = (1..100).collect::<Vec<u8>>();
+    let mut s = String::new();
+    for id in 1..=cards {
+        numbers.shuffle(&mut rng);
+        let winning = numbers[..10]
+            .iter()
+            .map(|n| format!("{n:2}"))
+            .collect::<Vec<_>>();
+        numbers.shuffle(&mut rng);
+        let mine = numbers[..25]
+            .iter()
+            .map(|n| format!("{n:2}"))
+            .collect::<Vec<_>>();
+        writeln!(s, "Card {id:3}: {} | {}", winning.join(" "), mine.join(" ")).unwrap();
+    }
+    s
+}
+
+pub fn day05(seeds: usize, ranges_per_map: usize) -> String {
+    const CATEGORIES: &[&str] = &[
+        "seed",
+        "soil",
+        "fertilizer",
+        "water",
+        "light",
+        "temperature",
+        "humidity",
+        "location",
+    ];
+    // Keep every range below 2^31 so no mapping can leave u32
+    const MAX: u32 = 1 << 31;
+    let mut rng = rng();
+    let seeds = (0..seeds)
+        .map(|_| rng.gen_range(0..MAX).to_string())
+        .collect::<Vec<_>>();
+    let mut s = format!("seeds: {}\n", seeds.join(" "));
+    for pair in CATEGORIES.windows(2) {
+        write!(s, "\n{}-to-{} map:\n", pair[0], pair[1]).unwrap();
+        for _ in 0..ranges_per_map {
+            let len = rng.gen_range(1..1 << 24);
+            let dest = rng.gen_range(0..MAX - len);
+            let src = rng.gen_range(0..MAX - len);
+            writeln!(s, "{dest} {src} {len}").unwrap();
+        }
+    }
+    s
+}
+
+pub fn day06() -> String {
+    // Part 2 concatenates the numbers, which overflows with more or longer
+    // races than this. Solving takes logarithmic time, so it's large enough
+    "Time:      62     73     75     65
+Distance:  644   1023   1240   1023"
+        .to_owned()
+}
+
+pub fn day07(hands: usize) -> String {
+    const CARDS: &[u8] = b"23456789TJQKA";
+    let mut rng = rng();
+    let mut s = String::new();
+    for _ in 0..hands {
+        let hand = (0..5)
+            .map(|_| char::from(*CARDS.choose(&mut rng).unwrap()))
+            .collect::<String>();
+        writeln!(s, "{hand} {}", rng.gen_range(1..1000)).unwrap();
+    }
+    s
+}
+
+pub fn day08(directions: usize, nodes: usize) -> String {
+    // A chain AAA -> ... -> ZZZ where the direction taken at each step leads
+    // to the next node and the other one leads anywhere else
+    let name = |i: usize| {
+        let i = i + 1;
+        let letter = |n: usize| char::from(b'A' + (n % 26) as u8);
+        format!("{}{}{}", letter(i / 676), letter(i / 26), letter(i))
+    };
+    assert!(nodes + 2 < 26 * 26 * 26, "too many nodes for three letters");
+    let mut rng = rng();
+    let lr = (0..directions)
+        .map(|_| if rng.gen() { 'L' } else { 'R' })
+        .collect::<Vec<_>>();
+    let mut chain = vec!["AAA".to_owned()];
+    chain.extend((0..nodes).map(name));
+    chain.push("ZZZ".to_owned());
+
+    let mut s = lr.iter().collect::<String>();
+    s.push_str("\n\n");
+    for (i, pair) in chain.windows(2).enumerate() {
+        let other = &chain[rng.gen_range(0..chain.len())];
+        let (left, right) = match lr[i % lr.len()] {
+            'L' => (&pair[1], other),
+            _ => (other, &pair[1]),
+        };
+        writeln!(s, "{} = ({left}, {right})", pair[0]).unwrap();
+    }
+    s.push_str("ZZZ = (ZZZ, ZZZ)\n");
+    s
+}
+
+pub fn day09(histories: usize, len: usize) -> String {
+    // Values of polynomials up to degree five, which take at most six rounds
+    // of differences to reach all zeroes
+    let mut rng = rng();
+    let mut s = String::new();
+    for _ in 0..histories {
+        let coefficients = (0..rng.gen_range(1..=6))
+            .map(|_| rng.gen_range(-9..=9))
+            .collect::<Vec<i64>>();
+        let values = (0..len as i64)
+            .map(|x| {
+                coefficients
+                    .iter()
+                    .rev()
+                    .fold(0, |acc, c| acc * x + c)
+                    .to_string()
+            })
+            .collect::<Vec<_>>();
+        writeln!(s, "{}", values.join(" ")).unwrap();
+    }
+    s
+}
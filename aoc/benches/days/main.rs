@@ -0,0 +1,118 @@
+//! One benchmark group per day, timing parse, part 1 and part 2 separately on
+//! the puzzle's example input and on a large generated one.
+//!
+//! Save a baseline before a change and compare against it afterwards:
+//!
+//! ```sh
+//! cargo bench -p aoc -- --save-baseline main
+//! cargo bench -p aoc -- --baseline main
+//! ```
+//!
+//! `baseline.sh compare` checks against the reference baseline committed in
+//! `baseline/` instead, failing on regressions.
+
+use aoc_common::{Error, Part, Solution};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+mod examples;
+mod generate;
+
+fn bench_day<S: Solution>(c: &mut Criterion, inputs: &[(&str, &str)]) {
+    let mut group = c.benchmark_group(format!("day{:02}", S::DAY));
+    for &(name, input) in inputs {
+        group.bench_with_input(BenchmarkId::new("parse", name), input, |b, input| {
+            b.iter(|| S::parse(black_box(input)).unwrap())
+        });
+
+        let parsed = S::parse(input).unwrap();
+        for part in Part::ALL {
+            let solve = match part {
+                Part::One => S::part1,
+                Part::Two => S::part2,
+            };
+            match solve(&parsed) {
+                Ok(_) => {}
+                Err(Error::Unsolved { .. }) => continue,
+                Err(e) => panic!("day {} part {part} fails on {name} input: {e}", S::DAY),
+            }
+            group.bench_with_input(
+                BenchmarkId::new(format!("part{part}"), name),
+                &parsed,
+                |b, parsed| b.iter(|| solve(black_box(parsed)).unwrap()),
+            );
+        }
+    }
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<aoc01::Day01>(
+        c,
+        &[
+            ("example", examples::DAY01),
+            ("large", &generate::day01(100_000)),
+        ],
+    );
+    bench_day::<aoc02::Day02>(
+        c,
+        &[
+            ("example", examples::DAY02),
+            ("large", &generate::day02(10_000)),
+        ],
+    );
+    // Part 1 is quadratic in the size of the schematic, so this is only
+    // about the size of a real input
+    bench_day::<aoc03::Day03>(
+        c,
+        &[
+            ("example", examples::DAY03),
+            ("large", &generate::day03(140)),
+        ],
+    );
+    bench_day::<aoc04::Day04>(
+        c,
+        &[
+            ("example", examples::DAY04),
+            ("large", &generate::day04(10_000)),
+        ],
+    );
+    bench_day::<aoc05::Day05>(
+        c,
+        &[
+            ("example", examples::DAY05),
+            ("large", &generate::day05(1_000, 200)),
+        ],
+    );
+    bench_day::<aoc06::Day06>(
+        c,
+        &[("example", examples::DAY06), ("large", &generate::day06())],
+    );
+    bench_day::<aoc07::Day07>(
+        c,
+        &[
+            ("example", examples::DAY07),
+            ("large", &generate::day07(10_000)),
+        ],
+    );
+    bench_day::<aoc08::Day08>(
+        c,
+        &[
+            ("example", examples::DAY08),
+            ("large", &generate::day08(300, 15_000)),
+        ],
+    );
+    bench_day::<aoc09::Day09>(
+        c,
+        &[
+            ("example", examples::DAY09),
+            ("large", &generate::day09(10_000, 21)),
+        ],
+    );
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(20);
+    targets = days
+}
+criterion_main!(benches);
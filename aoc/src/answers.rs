@@ -0,0 +1,144 @@
+use std::{fs, io, path::Path};
+
+use aoc_common::{Error, Part, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// File name of the known-answer registry, kept next to the inputs.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Known answers, keyed by day, part and a hash of the input they were
+/// computed from, so several inputs for the same day can be kept apart.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(default, rename = "answer")]
+    entries: Vec<Entry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Entry {
+    day: u8,
+    part: u8,
+    input: String,
+    answer: String,
+}
+
+/// How a freshly computed answer compares to the registry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check<'a> {
+    Matches,
+    Changed { expected: &'a str },
+    Unrecorded,
+}
+
+impl Answers {
+    /// Loads the registry at `path`, which is empty if the file doesn't exist.
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Answers::default()),
+            Err(source) => {
+                return Err(Error::Input {
+                    path: path.to_owned(),
+                    source,
+                })
+            }
+        };
+        toml::from_str(&text).map_err(|e| Error::format(format!("{}: {e}", path.display())))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let text = toml::to_string(self).expect("answers always serialize");
+        fs::write(path, text).map_err(|source| Error::Write {
+            path: path.to_owned(),
+            source,
+        })
+    }
+
+    pub fn check(&self, day: u8, part: Part, input: &str, answer: &str) -> Check<'_> {
+        match self.get(day, part, &input_hash(input)) {
+            Some(expected) if expected == answer => Check::Matches,
+            Some(expected) => Check::Changed { expected },
+            None => Check::Unrecorded,
+        }
+    }
+
+    /// Records `answer`, unless an answer for the same day, part and input is
+    /// already known. Returns whether anything was recorded.
+    pub fn record(&mut self, day: u8, part: Part, input: &str, answer: &str) -> bool {
+        let input = input_hash(input);
+        if self.get(day, part, &input).is_some() {
+            return false;
+        }
+        self.entries.push(Entry {
+            day,
+            part: part.into(),
+            input,
+            answer: answer.to_owned(),
+        });
+        self.entries.sort_by_key(|e| (e.day, e.part));
+        true
+    }
+
+    fn get(&self, day: u8, part: Part, input: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|e| e.day == day && e.part == u8::from(part) && e.input == input)
+            .map(|e| e.answer.as_str())
+    }
+}
+
+/// Hex-encoded SHA-256 of a puzzle input.
+pub fn input_hash(input: &str) -> String {
+    Sha256::digest(input)
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_checks_answers_per_input() {
+        let mut answers = Answers::default();
+        assert!(answers.record(6, Part::One, "input a", "288"));
+        assert!(!answers.record(6, Part::One, "input a", "289"));
+
+        assert_eq!(
+            Check::Matches,
+            answers.check(6, Part::One, "input a", "288")
+        );
+        assert_eq!(
+            Check::Changed { expected: "288" },
+            answers.check(6, Part::One, "input a", "289")
+        );
+        assert_eq!(
+            Check::Unrecorded,
+            answers.check(6, Part::Two, "input a", "288")
+        );
+        assert_eq!(
+            Check::Unrecorded,
+            answers.check(6, Part::One, "input b", "288")
+        );
+    }
+
+    #[test]
+    fn it_round_trips_through_toml() {
+        let mut answers = Answers::default();
+        answers.record(9, Part::Two, "0 3 6", "-3");
+        answers.record(1, Part::One, "1abc2", "12");
+        let text = toml::to_string(&answers).unwrap();
+        assert_eq!(
+            format!(
+                "[[answer]]\nday = 1\npart = 1\ninput = \"{}\"\nanswer = \"12\"\n\n\
+                 [[answer]]\nday = 9\npart = 2\ninput = \"{}\"\nanswer = \"-3\"\n",
+                input_hash("1abc2"),
+                input_hash("0 3 6"),
+            ),
+            text
+        );
+        assert_eq!(answers, toml::from_str(&text).unwrap());
+    }
+}
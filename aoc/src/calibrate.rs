@@ -0,0 +1,190 @@
+use std::{path::PathBuf, process::ExitCode};
+
+use aoc01::{
+    explain,
+    fuzzy::{FuzzyMatcher, FuzzyOptions},
+    lexicon::{DigitLexicon, Language},
+    numbers::{NumberPolicy, NumberWords},
+    rules::CalibrationRule,
+    scanner::Scanner,
+    stream::{self, StreamOptions},
+    unicode::UnicodeDigits,
+    Calibrator,
+};
+use aoc_common::{Error, InputSource, Part, Result};
+use clap::Args;
+
+#[derive(Debug, Args)]
+pub struct CalibrateArgs {
+    /// Part whose rules to use: 1 only counts digits, 2 also digit words
+    #[arg(short, long, default_value = "2")]
+    part: Part,
+
+    /// Calibration document, or - for stdin. Defaults to the day 1 input
+    #[arg(short, long)]
+    input: Option<InputSource>,
+
+    /// Language of the digit words, English by default. Part 2 only
+    #[arg(short, long, conflicts_with = "lexicon")]
+    language: Option<Language>,
+
+    /// File with digit words, one digit per line followed by its words. Part
+    /// 2 only
+    #[arg(long)]
+    lexicon: Option<PathBuf>,
+
+    /// Also read English numbers like "seventeen" or "twenty-one", and take
+    /// either their first and last digits or the whole first and last
+    /// numbers. Part 2 only
+    #[arg(long, value_name = "digits|whole", conflicts_with_all = ["language", "lexicon"])]
+    numbers: Option<NumberPolicy>,
+
+    /// Count decimal digits of every script, not just ASCII ones. Part 1 only
+    #[arg(long)]
+    unicode: bool,
+
+    /// Print every line with its first and last tokens marked
+    #[arg(long)]
+    explain: bool,
+
+    /// Also read digit words with up to this many typos, like "nien" or
+    /// "sevn". Words get one edit per three letters and short words none.
+    /// Part 2 only
+    #[arg(long, value_name = "MAX_EDITS", conflicts_with_all = ["numbers", "unicode"])]
+    fuzzy: Option<usize>,
+
+    /// Read digit words regardless of case, like "Nine" or "NINE". Part 2
+    /// only
+    #[arg(long, conflicts_with_all = ["numbers", "unicode"])]
+    ignore_case: bool,
+
+    /// How a line's digits make up its value: the first digit followed by the
+    /// last, every digit concatenated, the N-th digit from the start followed
+    /// by the N-th from the end, or the sum of the digits
+    #[arg(
+        long,
+        default_value = "first-last",
+        value_name = "first-last|concat|nth:N|sum",
+        conflicts_with_all = ["numbers", "unicode", "fuzzy", "ignore_case", "explain", "stream"]
+    )]
+    rule: CalibrationRule,
+
+    /// Fail if any line has no digit, instead of skipping it
+    #[arg(long)]
+    strict: bool,
+
+    /// Read the document a chunk at a time and sum chunks in parallel, for
+    /// documents too large to fit in memory
+    #[arg(long, conflicts_with_all = ["explain", "strict"])]
+    stream: bool,
+
+    /// Threads summing chunks with --stream. Defaults to one per CPU
+    #[arg(long, requires = "stream")]
+    threads: Option<usize>,
+}
+
+pub fn calibrate(args: CalibrateArgs) -> ExitCode {
+    match try_calibrate(args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn try_calibrate(args: CalibrateArgs) -> Result<()> {
+    let words = args.language.is_some() || args.lexicon.is_some() || args.numbers.is_some();
+    if args.part == Part::One && words {
+        return Err(Error::format(
+            "--language, --lexicon and --numbers only apply to part 2",
+        ));
+    }
+    let lexicon = match &args.lexicon {
+        Some(path) => DigitLexicon::from_file(path)?,
+        None => DigitLexicon::builtin(args.language.unwrap_or(Language::English)).clone(),
+    };
+    let numbers = args.numbers.map(NumberWords::new);
+    let fuzzy = (args.fuzzy.is_some() || args.ignore_case).then(|| {
+        let options = FuzzyOptions {
+            max_edits: args.fuzzy.unwrap_or(0),
+            ignore_case: args.ignore_case,
+        };
+        FuzzyMatcher::new(&lexicon, options)
+    });
+    let calibrator: &(dyn Calibrator + Sync) = match (args.part, &numbers, &fuzzy) {
+        (Part::One, _, Some(_)) => {
+            return Err(Error::format(
+                "--fuzzy and --ignore-case only apply to part 2",
+            ))
+        }
+        (Part::One, _, None) if args.unicode => &UnicodeDigits,
+        (Part::One, _, None) => Scanner::digits(),
+        (Part::Two, _, _) if args.unicode => {
+            return Err(Error::format("--unicode only applies to part 1"))
+        }
+        (Part::Two, Some(numbers), _) => numbers,
+        (Part::Two, None, Some(fuzzy)) => fuzzy,
+        (Part::Two, None, None) => &lexicon,
+    };
+    let source = args.input.unwrap_or_else(|| InputSource::for_day(1));
+
+    if args.stream {
+        let mut options = StreamOptions::default();
+        options.threads = args.threads.unwrap_or(options.threads);
+        let totals = stream::sum_calibration_values(source.open()?, options, |line| {
+            calibrator.calibrate(line).map(|c| c.value)
+        })
+        .map_err(|e| source.error(e))?;
+        println!("{}", totals.sum);
+        return Ok(());
+    }
+
+    let input = source.read()?;
+
+    if args.rule != CalibrationRule::FirstLast {
+        let scanner = match args.part {
+            Part::One => Scanner::digits(),
+            Part::Two => lexicon.scanner(),
+        };
+        let sum = match args.strict {
+            true => args.rule.strict_sum(scanner, &input)?,
+            false => args.rule.sum(scanner, &input)?,
+        };
+        println!("{sum}");
+        return Ok(());
+    }
+
+    let explanation = explain::explain(&input, calibrator);
+    if args.explain {
+        println!("{explanation}");
+    }
+    let sum = match args.strict {
+        true => explanation.strict_sum()?,
+        false => explanation.sum(),
+    };
+    if !args.explain {
+        println!("{sum}");
+    }
+    if let Some(fuzzy) = fuzzy {
+        review(&input, &fuzzy);
+    }
+    Ok(())
+}
+
+/// Lists the fuzzy tokens of every line on stderr, for checking they were
+/// read right.
+fn review(document: &str, fuzzy: &FuzzyMatcher<'_>) {
+    for (i, line) in document.lines().enumerate() {
+        for m in fuzzy.fuzzy_matches(line) {
+            let plural = if m.edits == 1 { "" } else { "s" };
+            eprintln!(
+                "line {}: {:?} read as {:?} ({} edit{plural})",
+                i + 1,
+                &line[m.token.start..m.token.end],
+                m.word,
+                m.edits
+            );
+        }
+    }
+}
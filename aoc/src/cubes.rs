@@ -0,0 +1,74 @@
+use std::process::ExitCode;
+
+use aoc02::{constraints::ConstraintSet, report, Cubes, Game};
+use aoc_common::{parse, InputSource, Result};
+use clap::Args;
+
+use crate::output::Format;
+
+#[derive(Debug, Args)]
+pub struct CubesArgs {
+    /// Games to check, or - for stdin. Defaults to the day 2 input
+    #[arg(short, long)]
+    input: Option<InputSource>,
+
+    /// Cubes in the bag, of any colours
+    #[arg(short, long, default_value = "12 red, 13 green, 14 blue")]
+    bag: String,
+
+    /// Which games are valid instead of the bag, e.g.
+    /// "red<=12 && total<=39 && red/blue<=2 && has green"
+    #[arg(long, conflicts_with_all = ["bag", "report"])]
+    constraints: Option<String>,
+
+    /// Show for each game whether it fits the bag, which reveals don't and
+    /// its smallest bag
+    #[arg(long)]
+    report: bool,
+
+    /// Report format
+    #[arg(long, value_enum, default_value_t = Format::Text, requires = "report")]
+    format: Format,
+}
+
+pub fn cubes(args: CubesArgs) -> ExitCode {
+    match try_cubes(args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn try_cubes(args: CubesArgs) -> Result<()> {
+    let bag = args.bag.parse::<Cubes>()?;
+    let input = args
+        .input
+        .unwrap_or_else(|| InputSource::for_day(2))
+        .read()?;
+    let games = parse::each_line::<Game, _>(&input)?;
+
+    if args.report {
+        let report = report::report(&games, &bag)?;
+        match args.format {
+            Format::Text => println!("{report}"),
+            Format::Json => println!("{}", serde_json::to_string(&report).unwrap()),
+        }
+        return Ok(());
+    }
+
+    let constraints = match &args.constraints {
+        Some(constraints) => constraints.parse::<ConstraintSet>()?,
+        None => ConstraintSet::from_bag(&bag)?,
+    };
+    println!(
+        "valid games: {}",
+        aoc02::sum_games_allowed_by(&games, &constraints)?
+    );
+    println!(
+        "power: {}",
+        aoc02::sum_power_of_smallest_valid_cubes(&games)?
+    );
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use aoc_common::{DynSolution, Solver};
+
+pub static SOLUTIONS: &[&dyn DynSolution] = &[
+    &Solver::<aoc01::Day01>::new(),
+    &Solver::<aoc02::Day02>::new(),
+    &Solver::<aoc03::Day03>::new(),
+    &Solver::<aoc04::Day04>::new(),
+    &Solver::<aoc05::Day05>::new(),
+    &Solver::<aoc06::Day06>::new(),
+    &Solver::<aoc07::Day07>::new(),
+    &Solver::<aoc08::Day08>::new(),
+    &Solver::<aoc09::Day09>::new(),
+];
+
+pub fn get(day: u8) -> Option<&'static dyn DynSolution> {
+    SOLUTIONS.iter().copied().find(|s| s.day() == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{Error, Part};
+
+    use super::*;
+
+    #[test]
+    fn it_registers_every_day_in_order() {
+        let days = SOLUTIONS.iter().map(|s| s.day()).collect::<Vec<_>>();
+        assert_eq!((1..=9).collect::<Vec<_>>(), days);
+    }
+
+    #[test]
+    fn it_dispatches_to_the_right_solver() {
+        let races = get(6)
+            .unwrap()
+            .parse("Time:      7  15   30\nDistance:  9  40  200")
+            .unwrap();
+        assert_eq!("288", races.solve(Part::One).unwrap());
+        assert_eq!("71503", races.solve(Part::Two).unwrap());
+    }
+
+    #[test]
+    fn it_reports_unsolved_parts() {
+        let schematic = get(3).unwrap().parse("467.\n...*").unwrap();
+        assert!(matches!(
+            schematic.solve(Part::Two),
+            Err(Error::Unsolved { day: 3, .. })
+        ));
+    }
+}
@@ -0,0 +1,179 @@
+use std::{path::PathBuf, process::ExitCode};
+
+use answers::Answers;
+use aoc_common::{InputSource, Part};
+use clap::{Args, Parser, Subcommand};
+use output::Format;
+use timings::{CountingAlloc, Timings};
+use tracing_subscriber::layer::SubscriberExt;
+
+mod answers;
+mod calibrate;
+mod cubes;
+mod days;
+mod output;
+mod run;
+mod timings;
+mod verify;
+
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc;
+
+#[derive(Debug, Parser)]
+#[command(about = "Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Solve one day, or every day with --all
+    Run(RunArgs),
+    /// Sum day 1 calibration values, with options for other languages
+    Calibrate(calibrate::CalibrateArgs),
+    /// Check day 2 games against a bag holding cubes of any colours
+    Cubes(cubes::CubesArgs),
+    /// Check every day's answers for its stored input against answers.toml
+    Verify(VerifyArgs),
+}
+
+#[derive(Debug, Args)]
+struct RunArgs {
+    /// Day to solve
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=9), required_unless_present = "all")]
+    day: Option<u8>,
+
+    /// Part to solve. Both parts are solved if omitted
+    #[arg(short, long)]
+    part: Option<Part>,
+
+    /// Puzzle input file, or - for stdin.
+    /// Defaults to $AOC_INPUT_DIR/dayNN.txt, where AOC_INPUT_DIR defaults to inputs
+    #[arg(short, long, conflicts_with = "all", verbatim_doc_comment)]
+    input: Option<InputSource>,
+
+    /// Solve every day
+    #[arg(long, conflicts_with = "day")]
+    all: bool,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Print wall time and allocations per phase of each day to stderr
+    #[arg(long)]
+    timings: bool,
+}
+
+#[derive(Debug, Args)]
+struct VerifyArgs {
+    /// Day to verify. Every day with an input is verified if omitted
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=9))]
+    day: Option<u8>,
+
+    /// Known answers file.
+    /// Defaults to $AOC_INPUT_DIR/answers.toml, where AOC_INPUT_DIR defaults to inputs
+    #[arg(long, verbatim_doc_comment)]
+    answers: Option<PathBuf>,
+
+    /// Record answers that aren't known yet. Known answers are never replaced
+    #[arg(long)]
+    record: bool,
+}
+
+fn main() -> ExitCode {
+    match Cli::parse().command {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => verify(args),
+        Command::Calibrate(args) => calibrate::calibrate(args),
+        Command::Cubes(args) => cubes::cubes(args),
+    }
+}
+
+fn run(args: RunArgs) -> ExitCode {
+    let solutions = match args.day {
+        Some(day) => vec![days::get(day).expect("clap only accepts solved days")],
+        None => days::SOLUTIONS.to_vec(),
+    };
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+
+    let timings = args.timings.then(|| {
+        let timings = Timings::default();
+        tracing::subscriber::set_global_default(
+            tracing_subscriber::registry().with(timings.clone()),
+        )
+        .expect("no other subscriber is set");
+        timings
+    });
+
+    let mut failed = false;
+    for solution in solutions {
+        let source = args
+            .input
+            .clone()
+            .unwrap_or_else(|| InputSource::for_day(solution.day()));
+        let run = run::run_day(solution, &source, &parts, args.part.is_none());
+        output::print(&run, args.format);
+        if let Some(phases) = timings.as_ref().map(Timings::take) {
+            if !phases.is_empty() {
+                eprintln!(
+                    "Day {} timings:
+{}",
+                    run.day,
+                    timings::table(&phases)
+                );
+            }
+        }
+        failed |= run.failed();
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn verify(args: VerifyArgs) -> ExitCode {
+    let path = args
+        .answers
+        .unwrap_or_else(|| aoc_common::input_dir().join(answers::ANSWERS_FILE));
+    let mut answers = match Answers::load(&path) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("error: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let solutions = match args.day {
+        Some(day) => vec![days::get(day).expect("clap only accepts solved days")],
+        None => days::SOLUTIONS.to_vec(),
+    };
+    let summary = verify::verify(&solutions, &mut answers, args.record);
+    if summary.recorded > 0 {
+        if let Err(e) = answers.save(&path) {
+            eprintln!("error: {e}");
+            return ExitCode::FAILURE;
+        }
+    }
+
+    println!(
+        "{} ok, {} changed, {} unrecorded, {} recorded, {} errors, {} days without input",
+        summary.matching,
+        summary.changed,
+        summary.unrecorded,
+        summary.recorded,
+        summary.errors,
+        summary.missing_inputs
+    );
+    if summary.failed() {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
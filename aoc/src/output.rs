@@ -0,0 +1,160 @@
+use std::time::Duration;
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::run::{DayRun, Stage};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// One line per answer, errors on stderr
+    Text,
+    /// One JSON record per line and part, errors included, or the report
+    Json,
+}
+
+/// A JSON output record. Failures before solving (reading or parsing the
+/// input) are repeated for every requested part.
+#[derive(Debug, Serialize)]
+struct Record<'a> {
+    day: u8,
+    part: u8,
+    answer: Option<&'a str>,
+    parse_ms: Option<f64>,
+    solve_ms: Option<f64>,
+    error: Option<ErrorRecord>,
+}
+
+#[derive(Debug, Serialize)]
+struct ErrorRecord {
+    stage: Stage,
+    message: String,
+}
+
+pub fn print(run: &DayRun, format: Format) {
+    match format {
+        Format::Text => print_text(run),
+        Format::Json => {
+            for record in records(run) {
+                println!("{}", serde_json::to_string(&record).unwrap());
+            }
+        }
+    }
+}
+
+fn print_text(run: &DayRun) {
+    let day = run.day;
+    match &run.parts {
+        Ok(parts) => {
+            for p in parts {
+                match &p.answer {
+                    Ok(answer) => println!("Day {day} part {}: {answer}", p.part),
+                    Err(e) => eprintln!("Day {day} part {}: error: {e}", p.part),
+                }
+            }
+        }
+        Err(e) => eprintln!("Day {day}: error: {}", e.error),
+    }
+}
+
+fn records(run: &DayRun) -> Vec<Record<'_>> {
+    let parse_ms = run.parse_time.map(millis);
+    match &run.parts {
+        Ok(parts) => parts
+            .iter()
+            .map(|p| Record {
+                day: run.day,
+                part: p.part.into(),
+                answer: p.answer.as_deref().ok(),
+                parse_ms,
+                solve_ms: Some(millis(p.solve_time)),
+                error: p.answer.as_ref().err().map(|e| ErrorRecord {
+                    stage: Stage::Solve,
+                    message: e.to_string(),
+                }),
+            })
+            .collect(),
+        Err(e) => run
+            .requested
+            .iter()
+            .map(|&part| Record {
+                day: run.day,
+                part: part.into(),
+                answer: None,
+                parse_ms,
+                solve_ms: None,
+                error: Some(ErrorRecord {
+                    stage: e.stage,
+                    message: e.error.to_string(),
+                }),
+            })
+            .collect(),
+    }
+}
+
+fn millis(d: Duration) -> f64 {
+    d.as_secs_f64() * 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{Error, Part};
+
+    use super::*;
+    use crate::run::{self, PartRun, StageError};
+
+    #[test]
+    fn it_repeats_parse_errors_for_every_part() {
+        let run = DayRun {
+            day: 6,
+            parse_time: Some(Duration::from_millis(2)),
+            requested: Part::ALL.to_vec(),
+            parts: Err(StageError {
+                stage: Stage::Parse,
+                error: Error::format("missing distances"),
+            }),
+        };
+        let json = records(&run)
+            .iter()
+            .map(|r| serde_json::to_string(r).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                r#"{"day":6,"part":1,"answer":null,"parse_ms":2.0,"solve_ms":null,"error":{"stage":"parse","message":"invalid format - missing distances"}}"#,
+                r#"{"day":6,"part":2,"answer":null,"parse_ms":2.0,"solve_ms":null,"error":{"stage":"parse","message":"invalid format - missing distances"}}"#,
+            ],
+            json
+        );
+    }
+
+    #[test]
+    fn it_leaves_out_unsolved_parts_on_failure_too() {
+        let day3 = crate::days::get(3).unwrap();
+        for input in ["467.\n...*", "467.\n.."] {
+            let run = run::run_input(day3, input, &Part::ALL, true);
+            let parts = records(&run).iter().map(|r| r.part).collect::<Vec<_>>();
+            assert_eq!(vec![1], parts, "{input:?}");
+        }
+
+        let run = run::run_input(day3, "467.\n..", &Part::ALL, false);
+        assert_eq!(2, records(&run).len());
+    }
+
+    #[test]
+    fn it_records_answers_with_timings() {
+        let run = DayRun {
+            day: 6,
+            parse_time: Some(Duration::from_micros(500)),
+            requested: vec![Part::One],
+            parts: Ok(vec![PartRun {
+                part: Part::One,
+                solve_time: Duration::from_millis(1),
+                answer: Ok("288".to_owned()),
+            }]),
+        };
+        assert_eq!(
+            r#"{"day":6,"part":1,"answer":"288","parse_ms":0.5,"solve_ms":1.0,"error":null}"#,
+            serde_json::to_string(&records(&run)[0]).unwrap()
+        );
+    }
+}
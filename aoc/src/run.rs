@@ -0,0 +1,128 @@
+use std::time::{Duration, Instant};
+
+use aoc_common::{DynSolution, Error, InputSource, Part};
+use serde::Serialize;
+
+/// Result of solving some parts of one day, with the time spent on each stage.
+#[derive(Debug)]
+pub struct DayRun {
+    pub day: u8,
+    pub parse_time: Option<Duration>,
+    /// The parts that were solved, or would have been had the input been read
+    /// and parsed.
+    pub requested: Vec<Part>,
+    pub parts: Result<Vec<PartRun>, StageError>,
+}
+
+#[derive(Debug)]
+pub struct PartRun {
+    pub part: Part,
+    pub solve_time: Duration,
+    pub answer: Result<String, Error>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Stage {
+    Input,
+    Parse,
+    Solve,
+}
+
+#[derive(Debug)]
+pub struct StageError {
+    pub stage: Stage,
+    pub error: Error,
+}
+
+/// Reads and parses the input once, then solves each of `parts`. Parts that
+/// aren't solved yet are left out when `skip_unsolved` is set, even if the
+/// input can't be read or parsed.
+pub fn run_day(
+    solution: &dyn DynSolution,
+    source: &InputSource,
+    parts: &[Part],
+    skip_unsolved: bool,
+) -> DayRun {
+    match source.read() {
+        Ok(input) => run_input(solution, &input, parts, skip_unsolved),
+        Err(error) => DayRun {
+            day: solution.day(),
+            parse_time: None,
+            requested: requested(solution, parts, skip_unsolved),
+            parts: Err(StageError {
+                stage: Stage::Input,
+                error,
+            }),
+        },
+    }
+}
+
+/// Like [`run_day`], for an input that has already been read.
+pub fn run_input(
+    solution: &dyn DynSolution,
+    input: &str,
+    parts: &[Part],
+    skip_unsolved: bool,
+) -> DayRun {
+    let day = solution.day();
+    let requested = requested(solution, parts, skip_unsolved);
+    let (parsed, parse_time) = timed(|| solution.parse(input));
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            return DayRun {
+                day,
+                parse_time: Some(parse_time),
+                requested,
+                parts: Err(StageError {
+                    stage: Stage::Parse,
+                    error,
+                }),
+            }
+        }
+    };
+
+    let parts = requested
+        .iter()
+        .map(|&part| {
+            let (answer, solve_time) = timed(|| parsed.solve(part));
+            PartRun {
+                part,
+                solve_time,
+                answer,
+            }
+        })
+        .filter(|run| !(skip_unsolved && matches!(run.answer, Err(Error::Unsolved { .. }))))
+        .collect();
+
+    DayRun {
+        day,
+        parse_time: Some(parse_time),
+        requested,
+        parts: Ok(parts),
+    }
+}
+
+fn requested(solution: &dyn DynSolution, parts: &[Part], skip_unsolved: bool) -> Vec<Part> {
+    parts
+        .iter()
+        .copied()
+        .filter(|part| !skip_unsolved || solution.parts().contains(part))
+        .collect()
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+impl DayRun {
+    pub fn failed(&self) -> bool {
+        match &self.parts {
+            Ok(parts) => parts.iter().any(|p| p.answer.is_err()),
+            Err(_) => true,
+        }
+    }
+}
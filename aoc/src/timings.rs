@@ -0,0 +1,284 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    fmt::Write,
+    ops::{AddAssign, Sub},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use tracing::{span, Subscriber};
+use tracing_subscriber::{layer::Context, registry::LookupSpan, Layer};
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED_BYTES: AtomicU64 = AtomicU64::new(0);
+
+thread_local! {
+    static UNCOUNTED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// The system allocator, counting every allocation so [`Timings`] can report
+/// them per phase.
+pub struct CountingAlloc;
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        count(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        count(layout.size());
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        count(new_size);
+        System.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+fn count(bytes: usize) {
+    // The thread local is gone while a thread shuts down
+    if UNCOUNTED.try_with(|u| !u.get()).unwrap_or(false) {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(bytes as u64, Ordering::Relaxed);
+    }
+}
+
+/// Runs `f` without counting its allocations, so bookkeeping doesn't show up
+/// in the phase being measured.
+fn uncounted<T>(f: impl FnOnce() -> T) -> T {
+    let was = UNCOUNTED.with(|u| u.replace(true));
+    let result = f();
+    UNCOUNTED.with(|u| u.set(was));
+    result
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Allocs {
+    pub count: u64,
+    pub bytes: u64,
+}
+
+impl Allocs {
+    fn now() -> Self {
+        Allocs {
+            count: ALLOCATIONS.load(Ordering::Relaxed),
+            bytes: ALLOCATED_BYTES.load(Ordering::Relaxed),
+        }
+    }
+}
+
+impl Sub for Allocs {
+    type Output = Allocs;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Allocs {
+            count: self.count - rhs.count,
+            bytes: self.bytes - rhs.bytes,
+        }
+    }
+}
+
+impl AddAssign for Allocs {
+    fn add_assign(&mut self, rhs: Self) {
+        self.count += rhs.count;
+        self.bytes += rhs.bytes;
+    }
+}
+
+/// Totals for every span with the same path of names from the root, e.g.
+/// `parse` > `category_map`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Phase {
+    pub path: Vec<&'static str>,
+    pub calls: u64,
+    pub time: Duration,
+    pub allocs: Allocs,
+}
+
+/// Tracing layer adding up wall time and allocations per [`Phase`]. Times
+/// include nested phases.
+#[derive(Debug, Clone, Default)]
+pub struct Timings {
+    phases: Arc<Mutex<Vec<Phase>>>,
+}
+
+struct PhaseIndex(usize);
+
+struct Entered {
+    start: Instant,
+    allocs: Allocs,
+}
+
+impl Timings {
+    /// The phases recorded so far, in the order they were first seen.
+    pub fn take(&self) -> Vec<Phase> {
+        std::mem::take(&mut self.phases.lock().unwrap())
+    }
+}
+
+impl<S> Layer<S> for Timings
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, _: &span::Attributes<'_>, id: &span::Id, ctx: Context<'_, S>) {
+        uncounted(|| {
+            let span = ctx.span(id).expect("new spans are registered");
+            let path = span.scope().from_root().map(|s| s.name()).collect();
+            let mut phases = self.phases.lock().unwrap();
+            let index = phases
+                .iter()
+                .position(|p| p.path == path)
+                .unwrap_or_else(|| {
+                    phases.push(Phase {
+                        path,
+                        calls: 0,
+                        time: Duration::ZERO,
+                        allocs: Allocs::default(),
+                    });
+                    phases.len() - 1
+                });
+            span.extensions_mut().insert(PhaseIndex(index));
+        })
+    }
+
+    fn on_enter(&self, id: &span::Id, ctx: Context<'_, S>) {
+        uncounted(|| {
+            if let Some(span) = ctx.span(id) {
+                span.extensions_mut().replace(Entered {
+                    start: Instant::now(),
+                    allocs: Allocs::now(),
+                });
+            }
+        })
+    }
+
+    fn on_exit(&self, id: &span::Id, ctx: Context<'_, S>) {
+        let (end, allocs) = (Instant::now(), Allocs::now());
+        uncounted(|| {
+            let Some(span) = ctx.span(id) else { return };
+            let mut extensions = span.extensions_mut();
+            let Some(entered) = extensions.remove::<Entered>() else {
+                return;
+            };
+            let Some(&mut PhaseIndex(index)) = extensions.get_mut() else {
+                return;
+            };
+            if let Some(phase) = self.phases.lock().unwrap().get_mut(index) {
+                phase.calls += 1;
+                phase.time += end - entered.start;
+                phase.allocs += allocs - entered.allocs;
+            }
+        })
+    }
+}
+
+/// Renders phases as a table, with nested phases indented under their parent.
+pub fn table(phases: &[Phase]) -> String {
+    let label = |p: &Phase| {
+        format!(
+            "{}{}",
+            "  ".repeat(p.path.len() - 1),
+            p.path.last().unwrap()
+        )
+    };
+    // Children go right after their parent, siblings stay in first-seen order
+    let mut rows = phases.to_vec();
+    let order = |p: &Phase| {
+        (1..=p.path.len())
+            .map(|len| {
+                phases
+                    .iter()
+                    .position(|q| q.path == p.path[..len])
+                    .unwrap_or(usize::MAX)
+            })
+            .collect::<Vec<_>>()
+    };
+    rows.sort_by_key(order);
+
+    let width = rows
+        .iter()
+        .map(|p| label(p).len())
+        .max()
+        .unwrap_or(0)
+        .max(5);
+    let mut s = format!(
+        "{:width$}  {:>7}  {:>10}  {:>8}  {:>10}\n",
+        "phase", "calls", "time (ms)", "allocs", "bytes"
+    );
+    for p in &rows {
+        writeln!(
+            s,
+            "{:width$}  {:>7}  {:>10.3}  {:>8}  {:>10}",
+            label(p),
+            p.calls,
+            p.time.as_secs_f64() * 1000.0,
+            p.allocs.count,
+            p.allocs.bytes
+        )
+        .unwrap();
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use tracing_subscriber::layer::SubscriberExt;
+
+    use super::*;
+
+    #[test]
+    fn it_adds_up_phases_by_path() {
+        let timings = Timings::default();
+        let subscriber = tracing_subscriber::registry().with(timings.clone());
+        tracing::subscriber::with_default(subscriber, || {
+            let _parse = tracing::info_span!("parse").entered();
+            for _ in 0..3 {
+                tracing::info_span!("line").in_scope(|| {});
+            }
+        });
+
+        let phases = timings
+            .take()
+            .into_iter()
+            .map(|p| (p.path, p.calls))
+            .collect::<Vec<_>>();
+        assert_eq!(vec![(vec!["parse"], 1), (vec!["parse", "line"], 3)], phases);
+        assert!(timings.take().is_empty());
+    }
+
+    #[test]
+    fn it_indents_nested_phases_in_first_seen_order() {
+        let phase = |path: &[&'static str], ms| Phase {
+            path: path.to_vec(),
+            calls: 1,
+            time: Duration::from_millis(ms),
+            allocs: Allocs {
+                count: 2,
+                bytes: 64,
+            },
+        };
+        let phases = [
+            phase(&["parse"], 3),
+            phase(&["part1"], 2),
+            phase(&["parse", "category_map"], 1),
+        ];
+        assert_eq!(
+            "phase             calls   time (ms)    allocs       bytes
+parse                 1       3.000         2          64
+  category_map        1       1.000         2          64
+part1                 1       2.000         2          64
+",
+            table(&phases)
+        );
+    }
+}
@@ -0,0 +1,88 @@
+use std::io;
+
+use aoc_common::{DynSolution, Error, InputSource, Part};
+
+use crate::{
+    answers::{Answers, Check},
+    run,
+};
+
+/// Tally of a verification run over several days.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Summary {
+    pub matching: usize,
+    pub changed: usize,
+    pub unrecorded: usize,
+    pub recorded: usize,
+    pub errors: usize,
+    pub missing_inputs: usize,
+}
+
+impl Summary {
+    pub fn failed(&self) -> bool {
+        self.changed > 0 || self.errors > 0
+    }
+}
+
+/// Solves every solved part of each day's stored input and compares the
+/// answers against the registry, recording unknown ones if `record` is set.
+/// Days without an input file are skipped.
+pub fn verify(solutions: &[&dyn DynSolution], answers: &mut Answers, record: bool) -> Summary {
+    let mut summary = Summary::default();
+    for &solution in solutions {
+        let day = solution.day();
+        let source = InputSource::for_day(day);
+        let input = match source.read() {
+            Ok(input) => input,
+            Err(Error::Input { source, .. }) if source.kind() == io::ErrorKind::NotFound => {
+                summary.missing_inputs += 1;
+                continue;
+            }
+            Err(e) => {
+                eprintln!("Day {day}: error: {e}");
+                summary.errors += 1;
+                continue;
+            }
+        };
+
+        let parts = match run::run_input(solution, &input, &Part::ALL, true).parts {
+            Ok(parts) => parts,
+            Err(e) => {
+                eprintln!("Day {day}: error: {}", e.error);
+                summary.errors += 1;
+                continue;
+            }
+        };
+        for p in parts {
+            let part = p.part;
+            let answer = match p.answer {
+                Ok(answer) => answer,
+                Err(e) => {
+                    eprintln!("Day {day} part {part}: error: {e}");
+                    summary.errors += 1;
+                    continue;
+                }
+            };
+            match answers.check(day, part, &input, &answer) {
+                Check::Matches => {
+                    println!("Day {day} part {part}: ok");
+                    summary.matching += 1;
+                }
+                Check::Changed { expected } => {
+                    eprintln!("Day {day} part {part}: CHANGED: expected {expected}, got {answer}");
+                    summary.changed += 1;
+                }
+                Check::Unrecorded if record => {
+                    answers.record(day, part, &input, &answer);
+                    println!("Day {day} part {part}: recorded {answer}");
+                    summary.recorded += 1;
+                }
+                Check::Unrecorded => {
+                    println!("Day {day} part {part}: no known answer, got {answer}");
+                    summary.unrecorded += 1;
+                }
+            }
+        }
+    }
+    summary
+}
@@ -0,0 +1,19 @@
+//! Replays the checked in fuzz corpus on stable, so inputs that once made a
+//! parser or solver panic stay covered by `cargo test`.
+
+use std::{fs, path::Path};
+
+#[path = "../../fuzz/src/lib.rs"]
+mod harness;
+
+#[test]
+fn corpus_does_not_panic() {
+    let corpus = Path::new(env!("CARGO_MANIFEST_DIR")).join("../fuzz/corpus");
+    for &(target, run) in harness::TARGETS {
+        let entries = fs::read_dir(corpus.join(target))
+            .unwrap_or_else(|e| panic!("missing corpus for {target}: {e}"));
+        for entry in entries {
+            run(&fs::read(entry.unwrap().path()).unwrap());
+        }
+    }
+}
@@ -0,0 +1,92 @@
+//! Compares the automaton behind `find_calibration_value_2` with the
+//! previous implementation, which rebuilt a string from every prefix of the
+//! line and searched it for all nine words.
+
+use aoc01::{lexicon::DigitLexicon, sum_calibration_values_2};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+const WORDS: &[&str] = &[
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+fn find_num_naive(s: &str, backwards: bool) -> Option<u32> {
+    let letters: Box<dyn Iterator<Item = _>> = if backwards {
+        Box::new(s.chars().rev())
+    } else {
+        Box::new(s.chars())
+    };
+
+    let mut iterated_chars = Vec::new();
+    for c in letters {
+        if c.is_ascii_digit() {
+            return c.to_digit(10);
+        }
+
+        iterated_chars.push(c);
+        let maybe_digit = if backwards {
+            iterated_chars.iter().rev().collect::<String>()
+        } else {
+            iterated_chars.iter().collect::<String>()
+        };
+        if let Some(i) = WORDS.iter().position(|&n| maybe_digit.contains(n)) {
+            return Some(i as u32 + 1);
+        }
+    }
+    None
+}
+
+fn sum_naive(s: &str) -> u32 {
+    s.lines()
+        .filter_map(|l| Some(find_num_naive(l, false)? * 10 + find_num_naive(l, true)?))
+        .sum()
+}
+
+/// Lines of mostly noise with a few digits and words, padded to `bytes`.
+fn document(bytes: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(1);
+    let mut s = String::with_capacity(bytes + 100);
+    while s.len() < bytes {
+        for _ in 0..rng.gen_range(10..60) {
+            match rng.gen_range(0..20) {
+                0 => s.push(char::from(rng.gen_range(b'0'..=b'9'))),
+                1 => s.push_str(WORDS.choose(&mut rng).unwrap()),
+                _ => s.push(char::from(rng.gen_range(b'a'..=b'z'))),
+            }
+        }
+        s.push('\n');
+    }
+    s
+}
+
+fn scanner(c: &mut Criterion) {
+    let mut group = c.benchmark_group("calibration_document");
+    for mb in [1, 4, 16] {
+        let doc = document(mb << 20);
+        assert_eq!(
+            sum_naive(&doc),
+            sum_calibration_values_2(&doc, DigitLexicon::english())
+        );
+        group.throughput(Throughput::Bytes(doc.len() as u64));
+        group.bench_with_input(
+            BenchmarkId::new("automaton", format!("{mb}MiB")),
+            &doc,
+            |b, doc| b.iter(|| sum_calibration_values_2(black_box(doc), DigitLexicon::english())),
+        );
+        if mb == 1 {
+            group.bench_with_input(
+                BenchmarkId::new("naive", format!("{mb}MiB")),
+                &doc,
+                |b, doc| b.iter(|| sum_naive(black_box(doc))),
+            );
+        }
+    }
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = scanner
+}
+criterion_main!(benches);
@@ -0,0 +1,176 @@
+//! Which tokens make up each line's calibration value, for checking a
+//! document by eye.
+
+use std::fmt;
+
+use crate::{
+    scanner::{Match, TokenKind},
+    Calibration, Calibrator, MissingDigits,
+};
+
+/// Calibration of a whole document, line by line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation<'a> {
+    pub lines: Vec<LineExplanation<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineExplanation<'a> {
+    /// 1-based line number in the document.
+    pub number: usize,
+    pub text: &'a str,
+    /// `None` if the line has no digit, so it doesn't count towards the sum.
+    pub calibration: Option<Calibration>,
+}
+
+/// Explains the calibration value of every line of `document`, as found by
+/// `calibrator`.
+pub fn explain<'a, C: Calibrator + ?Sized>(document: &'a str, calibrator: &C) -> Explanation<'a> {
+    let lines = document
+        .lines()
+        .enumerate()
+        .map(|(i, text)| LineExplanation {
+            number: i + 1,
+            text,
+            calibration: calibrator.calibrate(text),
+        })
+        .collect();
+    Explanation { lines }
+}
+
+impl Explanation<'_> {
+    /// Sum of the calibration values. Whole numbers can make these much larger
+    /// than two digits, hence the `u64`.
+    pub fn sum(&self) -> u64 {
+        self.lines
+            .iter()
+            .filter_map(|l| l.calibration)
+            .map(|c| u64::from(c.value))
+            .sum()
+    }
+
+    /// The sum, or the lines without any digit if there are some.
+    pub fn strict_sum(&self) -> Result<u64, MissingDigits> {
+        let lines = self.skipped().map(|l| l.number).collect::<Vec<_>>();
+        match lines.is_empty() {
+            true => Ok(self.sum()),
+            false => Err(MissingDigits { lines }),
+        }
+    }
+
+    /// Lines without any digit.
+    pub fn skipped(&self) -> impl Iterator<Item = &LineExplanation<'_>> {
+        self.lines.iter().filter(|l| l.calibration.is_none())
+    }
+}
+
+impl fmt::Display for TokenKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TokenKind::Digit => write!(f, "digit"),
+            TokenKind::Word => write!(f, "word"),
+            TokenKind::Fuzzy => write!(f, "fuzzy word"),
+        }
+    }
+}
+
+/// Each line followed by `^` under its first token and `~` under its last,
+/// where they don't overlap, then a summary of the sum and skipped lines.
+impl fmt::Display for Explanation<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self.lines.len().to_string().len();
+        let gutter = " ".repeat(width);
+        for line in &self.lines {
+            writeln!(f, "{:>width$} | {}", line.number, line.text)?;
+            let Some(Calibration { first, last, value }) = line.calibration else {
+                writeln!(f, "{gutter} | skipped, no digit")?;
+                continue;
+            };
+
+            let column = |offset| line.text[..offset].chars().count();
+            let mut marks = vec![' '; column(line.text.len())];
+            for (m, mark) in [(last, '~'), (first, '^')] {
+                marks[column(m.start)..column(m.end)].fill(mark);
+            }
+            let token = |m: Match| {
+                format!(
+                    "{:?} ({}, bytes {}..{})",
+                    &line.text[m.start..m.end],
+                    m.kind,
+                    m.start,
+                    m.end
+                )
+            };
+            writeln!(
+                f,
+                "{gutter} | {} {value} = {} + {}",
+                marks.iter().collect::<String>(),
+                token(first),
+                token(last)
+            )?;
+        }
+
+        let skipped = self.skipped().map(|l| l.number).collect::<Vec<_>>();
+        write!(f, "sum {} over {} lines", self.sum(), self.lines.len())?;
+        match skipped.as_slice() {
+            [] => Ok(()),
+            [line] => write!(f, ", skipped line {line}"),
+            lines => write!(
+                f,
+                ", skipped {} lines: {}",
+                lines.len(),
+                lines
+                    .iter()
+                    .map(|l| l.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexicon::DigitLexicon;
+
+    #[test]
+    fn it_explains_each_line() {
+        let explanation = explain("eightwo\nabc\n4nine", DigitLexicon::english());
+        assert_eq!(131, explanation.sum());
+        assert_eq!(
+            vec![2],
+            explanation.skipped().map(|l| l.number).collect::<Vec<_>>()
+        );
+
+        let c = explanation.lines[0].calibration.unwrap();
+        assert_eq!(
+            (0, 5, TokenKind::Word),
+            (c.first.start, c.first.end, c.first.kind)
+        );
+        assert_eq!(
+            (4, 7, TokenKind::Word),
+            (c.last.start, c.last.end, c.last.kind)
+        );
+        assert_eq!(82, c.value);
+
+        let c = explanation.lines[2].calibration.unwrap();
+        assert_eq!(TokenKind::Digit, c.first.kind);
+        assert_eq!(TokenKind::Word, c.last.kind);
+    }
+
+    #[test]
+    fn it_annotates_the_document() {
+        let explanation = explain("treb7uchet\nabc\nxtwone3", DigitLexicon::english());
+        assert_eq!(
+            r#"1 | treb7uchet
+  |     ^      77 = "7" (digit, bytes 4..5) + "7" (digit, bytes 4..5)
+2 | abc
+  | skipped, no digit
+3 | xtwone3
+  |  ^^^  ~ 23 = "two" (word, bytes 1..4) + "3" (digit, bytes 6..7)
+sum 100 over 3 lines, skipped line 2"#,
+            explanation.to_string()
+        );
+    }
+}
@@ -0,0 +1,270 @@
+//! Digit words with typos, like "nien" or "sevn", found within an edit
+//! distance of the words of a [`DigitLexicon`].
+
+use std::cmp::Reverse;
+
+use crate::{
+    lexicon::DigitLexicon,
+    scanner::{Match, TokenKind},
+    Calibration, Calibrator,
+};
+
+/// Words need this many letters per edit, and at least `MIN_LETTERS` to have
+/// any, as a single edit already turns words like "one" into ones like "on".
+const LETTERS_PER_EDIT: usize = 3;
+const MIN_LETTERS: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FuzzyOptions {
+    /// Most insertions, deletions, substitutions and swaps of adjacent
+    /// letters a word may have, further limited by its length.
+    pub max_edits: usize,
+    /// Whether "Nine" or "NINE" are read as "nine".
+    pub ignore_case: bool,
+}
+
+impl Default for FuzzyOptions {
+    fn default() -> Self {
+        FuzzyOptions {
+            max_edits: 1,
+            ignore_case: false,
+        }
+    }
+}
+
+/// Finds the digits and digit words of a lexicon like its scanner does, plus
+/// close misspellings of the words, which have [`TokenKind::Fuzzy`].
+#[derive(Debug, Clone, Copy)]
+pub struct FuzzyMatcher<'a> {
+    lexicon: &'a DigitLexicon,
+    options: FuzzyOptions,
+}
+
+/// A fuzzy token of a line, with the lexicon word it was read as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FuzzyMatch<'a> {
+    pub token: Match,
+    pub word: &'a str,
+    /// Edits between the token and `word`, 0 if they only differ in case.
+    pub edits: usize,
+}
+
+impl<'a> FuzzyMatcher<'a> {
+    pub fn new(lexicon: &'a DigitLexicon, options: FuzzyOptions) -> Self {
+        FuzzyMatcher { lexicon, options }
+    }
+
+    /// Every token of `line`, the exact ones the lexicon's scanner finds
+    /// followed by the [fuzzy ones](FuzzyMatcher::fuzzy_matches).
+    pub fn find(&self, line: &str) -> Vec<Match> {
+        let mut tokens = self.lexicon.scanner().find_iter(line).collect::<Vec<_>>();
+        let fuzzy = self.fuzzy(line, &tokens);
+        tokens.extend(fuzzy.into_iter().map(|f| f.token));
+        tokens
+    }
+
+    /// The fuzzy tokens of `line`, for reviewing how it was read. Tokens
+    /// containing or contained in an exact one are left out, as are ones
+    /// containing or contained in a closer fuzzy match of the same digit.
+    pub fn fuzzy_matches(&self, line: &str) -> Vec<FuzzyMatch<'a>> {
+        let exact = self.lexicon.scanner().find_iter(line).collect::<Vec<_>>();
+        self.fuzzy(line, &exact)
+    }
+
+    fn fuzzy(&self, line: &str, exact: &[Match]) -> Vec<FuzzyMatch<'a>> {
+        let chars = line.char_indices().collect::<Vec<_>>();
+        let mut fuzzy = Vec::new();
+        for (word, value) in self.lexicon.words() {
+            let word_chars = word.chars().collect::<Vec<_>>();
+            let budget = match word_chars.len() {
+                len if len < MIN_LETTERS => 0,
+                len => self.options.max_edits.min(len / LETTERS_PER_EDIT),
+            };
+            if budget == 0 && !self.options.ignore_case {
+                continue;
+            }
+            for start in 0..chars.len() {
+                let Some((end, edits)) = self.closest(&word_chars, &chars[start..], budget) else {
+                    continue;
+                };
+                let start_byte = chars[start].0;
+                let end_byte = chars.get(start + end).map_or(line.len(), |&(i, _)| i);
+                if &line[start_byte..end_byte] == word {
+                    continue;
+                }
+                fuzzy.push(FuzzyMatch {
+                    token: Match {
+                        start: start_byte,
+                        end: end_byte,
+                        value: *value,
+                        kind: TokenKind::Fuzzy,
+                    },
+                    word,
+                    edits,
+                });
+            }
+        }
+
+        let within = |a: Match, b: Match| b.start <= a.start && a.end <= b.end;
+        let nested = |a: Match, b: Match| within(a, b) || within(b, a);
+        fuzzy
+            .iter()
+            .copied()
+            .filter(|f| {
+                !exact.iter().any(|&e| nested(f.token, e))
+                    && !fuzzy.iter().any(|g| {
+                        g.token != f.token
+                            && g.token.value == f.token.value
+                            && nested(f.token, g.token)
+                            && (g.edits, Reverse(g.token.end - g.token.start))
+                                < (f.edits, Reverse(f.token.end - f.token.start))
+                    })
+            })
+            .collect()
+    }
+
+    /// The length in chars of the prefix of `text` closest to `word`, with
+    /// the number of edits to it, if at most `budget`. Ties go to the longer
+    /// prefix.
+    fn closest(
+        &self,
+        word: &[char],
+        text: &[(usize, char)],
+        budget: usize,
+    ) -> Option<(usize, usize)> {
+        let eq = |a: char, b: char| {
+            a == b || (self.options.ignore_case && a.to_lowercase().eq(b.to_lowercase()))
+        };
+        // Optimal string alignment distance, one row per char of `text`
+        let mut before = Vec::new();
+        let mut previous = (0..=word.len()).collect::<Vec<_>>();
+        let mut best = None;
+        for (j, &(_, c)) in text.iter().take(word.len() + budget).enumerate() {
+            let mut row = vec![j + 1; word.len() + 1];
+            for i in 1..=word.len() {
+                let cost = usize::from(!eq(word[i - 1], c));
+                row[i] = (previous[i] + 1)
+                    .min(row[i - 1] + 1)
+                    .min(previous[i - 1] + cost);
+                if i > 1 && j > 0 && eq(word[i - 1], text[j - 1].1) && eq(word[i - 2], c) {
+                    row[i] = row[i].min(before[i - 2] + 1);
+                }
+            }
+            let edits = row[word.len()];
+            if edits <= budget && best.is_none_or(|(_, e)| edits <= e) {
+                best = Some((j + 1, edits));
+            }
+            if row.iter().all(|&d| d > budget) {
+                break;
+            }
+            before = std::mem::replace(&mut previous, row);
+        }
+        best
+    }
+}
+
+impl Calibrator for FuzzyMatcher<'_> {
+    /// Like [`Scanner::first_and_last`](crate::scanner::Scanner::first_and_last),
+    /// the first token is the one ending first and the last the one starting
+    /// last, the longest one on ties.
+    fn calibrate(&self, line: &str) -> Option<Calibration> {
+        let tokens = self.find(line);
+        let first = *tokens.iter().min_by_key(|m| (m.end, Reverse(m.start)))?;
+        let last = *tokens.iter().max_by_key(|m| (m.start, m.end))?;
+        Some(Calibration {
+            first,
+            last,
+            value: first.value * 10 + last.value,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{find_calibration_value_2, lexicon::Language, sum_calibration_values_2};
+
+    fn fuzzy(options: FuzzyOptions, line: &str) -> Vec<(&str, u32, usize)> {
+        let matcher = FuzzyMatcher::new(DigitLexicon::english(), options);
+        let mut found = matcher
+            .fuzzy_matches(line)
+            .into_iter()
+            .map(|t| (&line[t.token.start..t.token.end], t.token.value, t.edits))
+            .collect::<Vec<_>>();
+        found.sort();
+        found
+    }
+
+    #[test]
+    fn it_finds_misspelled_words() {
+        let options = FuzzyOptions::default();
+        assert_eq!(vec![("nien", 9, 1)], fuzzy(options, "xnien"));
+        assert_eq!(vec![("sevn", 7, 1)], fuzzy(options, "sevn2"));
+        assert_eq!(vec![("eigt", 8, 1)], fuzzy(options, "eigtwo"));
+        assert_eq!(vec![("Nine", 9, 1)], fuzzy(options, "Nine"));
+        assert!(fuzzy(options, "on").is_empty());
+        assert!(fuzzy(options, "seven").is_empty());
+        assert_eq!(vec![("nnie", 9, 1)], fuzzy(options, "nnie"));
+        assert!(fuzzy(options, "mien").is_empty());
+
+        let options = FuzzyOptions {
+            max_edits: 2,
+            ignore_case: true,
+        };
+        assert_eq!(vec![("NINE", 9, 0)], fuzzy(options, "NINE"));
+        assert_eq!(vec![("Sevn", 7, 1)], fuzzy(options, "Sevn"));
+        assert!(fuzzy(options, "Svn").is_empty());
+    }
+
+    #[test]
+    fn it_allows_more_edits_in_longer_words() {
+        let german = DigitLexicon::builtin(Language::German);
+        let find = |max_edits| {
+            let options = FuzzyOptions {
+                max_edits,
+                ignore_case: false,
+            };
+            FuzzyMatcher::new(german, options)
+                .fuzzy_matches("xsibnx")
+                .into_iter()
+                .map(|f| (f.word, f.edits))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(vec![("sieben", 2)], find(2));
+        assert!(find(1).is_empty());
+    }
+
+    #[test]
+    fn it_calibrates_with_fuzzy_words() {
+        let matcher = FuzzyMatcher::new(DigitLexicon::english(), FuzzyOptions::default());
+        let c = matcher.calibrate("sevn1nien").unwrap();
+        assert_eq!(79, c.value);
+        assert_eq!(
+            (TokenKind::Fuzzy, TokenKind::Fuzzy),
+            (c.first.kind, c.last.kind)
+        );
+        assert_eq!(Some(82), find_calibration_value_2("eigtwo", &matcher));
+        assert_eq!(
+            Some(11),
+            find_calibration_value_2("sevn1nien", DigitLexicon::english())
+        );
+    }
+
+    #[test]
+    fn it_agrees_with_part_2_without_edits() {
+        let doc = "two1nine\neightwothree\nabcone2threexyz\nxtwone3four\n4nineeightseven2\nzoneight234\n7pqrstsixteen";
+        let options = FuzzyOptions {
+            max_edits: 0,
+            ignore_case: false,
+        };
+        let matcher = FuzzyMatcher::new(DigitLexicon::english(), options);
+        assert_eq!(281, sum_calibration_values_2(doc, &matcher));
+        assert_eq!(
+            281,
+            sum_calibration_values_2(
+                doc,
+                &FuzzyMatcher::new(DigitLexicon::english(), FuzzyOptions::default())
+            )
+        );
+    }
+}
@@ -0,0 +1,283 @@
+//! Synthetic calibration documents with known answers, for stress and
+//! property tests.
+
+use std::ops::RangeInclusive;
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// Characters that can't be part of any digit word, so noise never adds
+/// digits to a line.
+const NOISE: &[char] = &[
+    'a', 'b', 'c', 'd', 'j', 'k', 'l', 'm', 'p', 'q', 'y', 'z', 'A', 'Q', '-', ' ', 'é', 'ß',
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeneratorOptions {
+    /// Digits between a line's first and last ones.
+    pub inner_digits: RangeInclusive<usize>,
+    /// Chance of a digit from 1 to 9 being written as a word. 0 is always
+    /// written as a digit.
+    pub word_density: f64,
+    /// Chance of a word sharing its first letter with the last letter of the
+    /// word before it, as in "eightwo" or "twone", where the two allow it.
+    pub overlap_density: f64,
+    /// Noise characters before, between and after the digits.
+    pub noise: RangeInclusive<usize>,
+    /// Chance of a [document](Generator::document) line having no digit at
+    /// all.
+    pub missing_density: f64,
+}
+
+impl Default for GeneratorOptions {
+    fn default() -> Self {
+        GeneratorOptions {
+            inner_digits: 0..=4,
+            word_density: 0.5,
+            overlap_density: 0.3,
+            noise: 0..=3,
+            missing_density: 0.05,
+        }
+    }
+}
+
+/// What the puzzle parts should make of a generated line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Expected {
+    /// Calibration value from ASCII digits only.
+    pub value_1: Option<u32>,
+    /// Calibration value from digits and English digit words.
+    pub value_2: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneratedLine {
+    pub text: String,
+    pub expected: Expected,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Document {
+    pub text: String,
+    /// One per line of `text`.
+    pub expected: Vec<Expected>,
+}
+
+impl Document {
+    pub fn sum_1(&self) -> u64 {
+        self.expected
+            .iter()
+            .filter_map(|e| e.value_1)
+            .map(u64::from)
+            .sum()
+    }
+
+    pub fn sum_2(&self) -> u64 {
+        self.expected
+            .iter()
+            .filter_map(|e| e.value_2)
+            .map(u64::from)
+            .sum()
+    }
+}
+
+/// Seeded generator, so a failing document can be generated again.
+#[derive(Debug, Clone)]
+pub struct Generator {
+    rng: StdRng,
+    options: GeneratorOptions,
+}
+
+impl Generator {
+    pub fn new(seed: u64, options: GeneratorOptions) -> Self {
+        Generator {
+            rng: StdRng::seed_from_u64(seed),
+            options,
+        }
+    }
+
+    /// A line whose part 2 calibration value is `value`, from 0 to 99.
+    ///
+    /// # Panics
+    ///
+    /// If `value` is over 99.
+    pub fn line(&mut self, value: u32) -> GeneratedLine {
+        assert!(value < 100, "calibration values have two digits");
+        let inner = self.rng.gen_range(self.options.inner_digits.clone());
+        let mut digits = vec![value / 10];
+        digits.extend((0..inner).map(|_| self.rng.gen_range(0..10)));
+        digits.push(value % 10);
+
+        let mut text = String::new();
+        let mut ascii = Vec::new();
+        let mut previous_word: Option<&str> = None;
+        for digit in digits {
+            let word = (digit > 0 && self.rng.gen_bool(self.options.word_density))
+                .then(|| WORDS[digit as usize - 1]);
+            match (previous_word, word) {
+                (Some(previous), Some(word))
+                    if previous.ends_with(&word[..1])
+                        && self.rng.gen_bool(self.options.overlap_density) =>
+                {
+                    text.push_str(&word[1..]);
+                }
+                _ => {
+                    self.push_noise(&mut text);
+                    match word {
+                        Some(word) => text.push_str(word),
+                        None => {
+                            text.push(char::from_digit(digit, 10).unwrap());
+                            ascii.push(digit);
+                        }
+                    }
+                }
+            }
+            previous_word = word;
+        }
+        self.push_noise(&mut text);
+
+        let value_1 = ascii.first().zip(ascii.last()).map(|(a, b)| a * 10 + b);
+        GeneratedLine {
+            text,
+            expected: Expected {
+                value_1,
+                value_2: Some(value),
+            },
+        }
+    }
+
+    /// A line of noise only, without any digit.
+    pub fn missing_line(&mut self) -> GeneratedLine {
+        let mut text = String::new();
+        self.push_noise(&mut text);
+        GeneratedLine {
+            text,
+            expected: Expected {
+                value_1: None,
+                value_2: None,
+            },
+        }
+    }
+
+    /// A document of `lines` lines with random calibration values.
+    pub fn document(&mut self, lines: usize) -> Document {
+        let mut document = Document {
+            text: String::new(),
+            expected: Vec::with_capacity(lines),
+        };
+        for _ in 0..lines {
+            let line = match self.rng.gen_bool(self.options.missing_density) {
+                true => self.missing_line(),
+                false => {
+                    let value = self.rng.gen_range(0..100);
+                    self.line(value)
+                }
+            };
+            document.text.push_str(&line.text);
+            document.text.push('\n');
+            document.expected.push(line.expected);
+        }
+        document
+    }
+
+    fn push_noise(&mut self, text: &mut String) {
+        for _ in 0..self.rng.gen_range(self.options.noise.clone()) {
+            text.push(*NOISE.choose(&mut self.rng).unwrap());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        find_calibration_value_1, find_calibration_value_2, lexicon::DigitLexicon,
+        sum_calibration_values_1, sum_calibration_values_2,
+    };
+
+    fn options() -> impl Iterator<Item = GeneratorOptions> {
+        [
+            GeneratorOptions::default(),
+            GeneratorOptions {
+                inner_digits: 0..=0,
+                word_density: 1.0,
+                overlap_density: 1.0,
+                noise: 0..=0,
+                missing_density: 0.0,
+            },
+            GeneratorOptions {
+                inner_digits: 5..=20,
+                word_density: 0.9,
+                overlap_density: 0.8,
+                noise: 0..=1,
+                missing_density: 0.2,
+            },
+            GeneratorOptions {
+                word_density: 0.0,
+                noise: 5..=10,
+                ..GeneratorOptions::default()
+            },
+        ]
+        .into_iter()
+    }
+
+    #[test]
+    fn it_generates_lines_with_the_requested_value() {
+        let lexicon = DigitLexicon::english();
+        for (seed, options) in options().enumerate() {
+            let mut generator = Generator::new(seed as u64, options);
+            for value in 0..100 {
+                let line = generator.line(value);
+                assert_eq!(
+                    Some(value),
+                    find_calibration_value_2(&line.text, lexicon),
+                    "{line:?}"
+                );
+                assert_eq!(
+                    line.expected.value_1,
+                    find_calibration_value_1(&line.text),
+                    "{line:?}"
+                );
+            }
+            let line = generator.missing_line();
+            assert_eq!(None, find_calibration_value_2(&line.text, lexicon));
+        }
+    }
+
+    #[test]
+    fn it_overlaps_words() {
+        let options = GeneratorOptions {
+            inner_digits: 0..=0,
+            word_density: 1.0,
+            overlap_density: 1.0,
+            noise: 0..=0,
+            missing_density: 0.0,
+        };
+        let mut generator = Generator::new(0, options);
+        assert_eq!("eightwo", generator.line(82).text);
+        assert_eq!("twone", generator.line(21).text);
+        assert_eq!("sevenine", generator.line(79).text);
+    }
+
+    #[test]
+    fn it_knows_the_sums_of_documents() {
+        let lexicon = DigitLexicon::english();
+        for seed in 0..50 {
+            for options in options() {
+                let document = Generator::new(seed, options).document(100);
+                assert_eq!(100, document.text.lines().count());
+                assert_eq!(
+                    document.sum_1(),
+                    u64::from(sum_calibration_values_1(&document.text))
+                );
+                assert_eq!(
+                    document.sum_2(),
+                    u64::from(sum_calibration_values_2(&document.text, lexicon))
+                );
+            }
+        }
+    }
+}
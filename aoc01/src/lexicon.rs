@@ -0,0 +1,303 @@
+//! Words spelling out the digits zero to nine, in several languages.
+
+use std::{fmt, fs, num::ParseIntError, path::Path, str::FromStr};
+
+use aoc_common::{Error, Result};
+use once_cell::sync::Lazy;
+
+use crate::{
+    scanner::{self, Pattern, Scanner, TokenKind},
+    Calibration, Calibrator,
+};
+
+const ENGLISH: &[(&str, u32)] = &[
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+const GERMAN: &[(&str, u32)] = &[
+    ("eins", 1),
+    ("zwei", 2),
+    ("drei", 3),
+    ("vier", 4),
+    ("fünf", 5),
+    ("sechs", 6),
+    ("sieben", 7),
+    ("acht", 8),
+    ("neun", 9),
+];
+const FRENCH: &[(&str, u32)] = &[
+    ("un", 1),
+    ("deux", 2),
+    ("trois", 3),
+    ("quatre", 4),
+    ("cinq", 5),
+    ("six", 6),
+    ("sept", 7),
+    ("huit", 8),
+    ("neuf", 9),
+];
+const SPANISH: &[(&str, u32)] = &[
+    ("uno", 1),
+    ("dos", 2),
+    ("tres", 3),
+    ("cuatro", 4),
+    ("cinco", 5),
+    ("seis", 6),
+    ("siete", 7),
+    ("ocho", 8),
+    ("nueve", 9),
+];
+// Bokmål and nynorsk spellings
+const NORWEGIAN: &[(&str, u32)] = &[
+    ("en", 1),
+    ("ett", 1),
+    ("ein", 1),
+    ("eitt", 1),
+    ("to", 2),
+    ("tre", 3),
+    ("fire", 4),
+    ("fem", 5),
+    ("seks", 6),
+    ("sju", 7),
+    ("syv", 7),
+    ("åtte", 8),
+    ("ni", 9),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Language {
+    English,
+    German,
+    French,
+    Spanish,
+    Norwegian,
+}
+
+impl Language {
+    pub const ALL: [Language; 5] = [
+        Language::English,
+        Language::German,
+        Language::French,
+        Language::Spanish,
+        Language::Norwegian,
+    ];
+
+    /// ISO 639-1 code.
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::German => "de",
+            Language::French => "fr",
+            Language::Spanish => "es",
+            Language::Norwegian => "no",
+        }
+    }
+
+    fn words(self) -> &'static [(&'static str, u32)] {
+        match self {
+            Language::English => ENGLISH,
+            Language::German => GERMAN,
+            Language::French => FRENCH,
+            Language::Spanish => SPANISH,
+            Language::Norwegian => NORWEGIAN,
+        }
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Language::English => "english",
+            Language::German => "german",
+            Language::French => "french",
+            Language::Spanish => "spanish",
+            Language::Norwegian => "norwegian",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl FromStr for Language {
+    type Err = Error;
+
+    /// Accepts the English name or the ISO 639-1 code, e.g. `german` or `de`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Language::ALL
+            .into_iter()
+            .find(|l| s.eq_ignore_ascii_case(l.code()) || s.eq_ignore_ascii_case(&l.to_string()))
+            .ok_or_else(|| Error::format(format!("unknown language '{s}'")))
+    }
+}
+
+/// The words for each digit in some language, together with the scanner
+/// finding them and the ASCII digits in a line.
+#[derive(Debug, Clone)]
+pub struct DigitLexicon {
+    words: Vec<(String, u32)>,
+    scanner: Scanner,
+}
+
+pub type ParseError = aoc_common::ParseError<ParseErrorKind>;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseErrorKind {
+    #[error("invalid digit: {0}")]
+    InvalidDigit(ParseIntError),
+    #[error("expected a digit from 0 to 9")]
+    DigitOutOfRange,
+    #[error("expected at least one word after the digit")]
+    MissingWords,
+    #[error("expected at least one word")]
+    Empty,
+}
+
+impl DigitLexicon {
+    /// A lexicon of `(word, digit)` pairs. A digit can have several words.
+    pub fn new<W: Into<String>>(words: impl IntoIterator<Item = (W, u32)>) -> Self {
+        let words = words
+            .into_iter()
+            .map(|(w, d)| (w.into(), d))
+            .collect::<Vec<_>>();
+        let patterns = words
+            .iter()
+            .map(|(w, d)| Pattern::new(w.as_str(), *d, TokenKind::Word));
+        let scanner = Scanner::new(scanner::digit_patterns().chain(patterns));
+        DigitLexicon { words, scanner }
+    }
+
+    pub fn builtin(language: Language) -> &'static DigitLexicon {
+        static LEXICONS: Lazy<Vec<DigitLexicon>> = Lazy::new(|| {
+            Language::ALL
+                .into_iter()
+                .map(|l| DigitLexicon::new(l.words().iter().copied()))
+                .collect()
+        });
+        &LEXICONS[Language::ALL.iter().position(|&l| l == language).unwrap()]
+    }
+
+    pub fn english() -> &'static DigitLexicon {
+        Self::builtin(Language::English)
+    }
+
+    /// Reads a lexicon in the format of [`DigitLexicon::from_str`].
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path).map_err(|source| Error::Input {
+            path: path.to_owned(),
+            source,
+        })?;
+        Ok(text.parse()?)
+    }
+
+    /// `(word, digit)` pairs, in the order they were given.
+    pub fn words(&self) -> &[(String, u32)] {
+        &self.words
+    }
+
+    pub fn scanner(&self) -> &Scanner {
+        &self.scanner
+    }
+}
+
+impl Calibrator for DigitLexicon {
+    fn calibrate(&self, line: &str) -> Option<Calibration> {
+        self.scanner.calibrate(line)
+    }
+}
+
+impl FromStr for DigitLexicon {
+    type Err = ParseError;
+
+    /// Parses one digit per line followed by its words, separated by
+    /// whitespace, e.g. `7 sju syv`. Blank lines and lines starting with `#`
+    /// are skipped.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut words = Vec::new();
+        for line in s.lines() {
+            let mut tokens = line.split_whitespace();
+            let Some(digit) = tokens.next().filter(|t| !t.starts_with('#')) else {
+                continue;
+            };
+            let value = digit
+                .parse::<u32>()
+                .map_err(|e| ParseError::new(ParseErrorKind::InvalidDigit(e), s, digit))?;
+            if !(0..=9).contains(&value) {
+                return Err(ParseError::new(ParseErrorKind::DigitOutOfRange, s, digit));
+            }
+            let len = words.len();
+            words.extend(tokens.map(|w| (w, value)));
+            if words.len() == len {
+                return Err(ParseError::new(
+                    ParseErrorKind::MissingWords,
+                    s,
+                    &line[line.len()..],
+                ));
+            }
+        }
+        if words.is_empty() {
+            return Err(ParseError::new(ParseErrorKind::Empty, s, s));
+        }
+        Ok(DigitLexicon::new(words))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Span;
+
+    use super::*;
+    use crate::find_calibration_value_2;
+
+    #[test]
+    fn it_has_builtin_languages() {
+        let value = |language, s| find_calibration_value_2(s, DigitLexicon::builtin(language));
+        assert_eq!(Some(83), value(Language::English, "eightwothree"));
+        assert_eq!(
+            Some(57),
+            value(Language::German, "xfünfzehnsiebenundzwanzigsieben")
+        );
+        assert_eq!(Some(96), value(Language::French, "neuf2troisix"));
+        assert_eq!(Some(14), value(Language::Spanish, "unocuatro"));
+        assert_eq!(Some(78), value(Language::Norwegian, "syvåtte"));
+        assert_eq!(None, value(Language::German, "onetwothree"));
+    }
+
+    #[test]
+    fn it_parses_language_names_and_codes() {
+        assert_eq!(Language::Norwegian, "no".parse().unwrap());
+        assert_eq!(Language::German, "German".parse().unwrap());
+        assert!("klingon".parse::<Language>().is_err());
+    }
+
+    #[test]
+    fn it_parses_user_lexicons() {
+        let lexicon = "# Dutch\n1 een\n2 twee\n\n3 drie\n"
+            .parse::<DigitLexicon>()
+            .unwrap();
+        assert_eq!(3, lexicon.words().len());
+        assert_eq!(Some(31), find_calibration_value_2("driexeen", &lexicon));
+
+        let lexicon = "0 nul\n1 een".parse::<DigitLexicon>().unwrap();
+        assert_eq!(Some(10), find_calibration_value_2("eennul", &lexicon));
+    }
+
+    #[test]
+    fn it_points_at_invalid_lexicon_lines() {
+        let e = "1 one\n10 ten".parse::<DigitLexicon>().unwrap_err();
+        assert_eq!(&ParseErrorKind::DigitOutOfRange, e.kind());
+        assert_eq!(Span { line: 2, column: 1 }, e.span());
+
+        let e = "1 one\n2".parse::<DigitLexicon>().unwrap_err();
+        assert_eq!(&ParseErrorKind::MissingWords, e.kind());
+        assert_eq!(Span { line: 2, column: 2 }, e.span());
+
+        let e = "# nothing".parse::<DigitLexicon>().unwrap_err();
+        assert_eq!(&ParseErrorKind::Empty, e.kind());
+    }
+}
@@ -0,0 +1,246 @@
+use std::fmt;
+
+use aoc_common::{Error, Result, Solution};
+use lexicon::DigitLexicon;
+use scanner::{Match, Scanner};
+use unicode::UnicodeDigits;
+
+pub mod explain;
+pub mod fuzzy;
+pub mod generate;
+pub mod lexicon;
+pub mod numbers;
+pub mod rules;
+pub mod scanner;
+pub mod stream;
+pub mod unicode;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = String;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.to_owned())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer> {
+        Ok(sum_calibration_values_1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer> {
+        Ok(sum_calibration_values_2(input, DigitLexicon::english()))
+    }
+}
+
+pub fn sum_calibration_values_1(s: &str) -> u32 {
+    s.lines().filter_map(find_calibration_value_1).sum()
+}
+
+pub fn sum_calibration_values_2<C: Calibrator + ?Sized>(s: &str, calibrator: &C) -> u32 {
+    s.lines()
+        .filter_map(|l| find_calibration_value_2(l, calibrator))
+        .sum()
+}
+
+/// Like [`sum_calibration_values_1`], but with decimal digits of any script
+/// rather than only ASCII ones.
+pub fn sum_calibration_values_1_unicode(s: &str) -> u32 {
+    s.lines().filter_map(find_calibration_value_1_unicode).sum()
+}
+
+/// Like [`sum_calibration_values_1`], but fails if any line has no digit.
+pub fn sum_calibration_values_1_strict(s: &str) -> Result<u32, MissingDigits> {
+    strict_sum(s.lines().map(find_calibration_value_1))
+}
+
+/// Like [`sum_calibration_values_2`], but fails if any line has no digit or
+/// digit word.
+pub fn sum_calibration_values_2_strict<C: Calibrator + ?Sized>(
+    s: &str,
+    calibrator: &C,
+) -> Result<u32, MissingDigits> {
+    strict_sum(s.lines().map(|l| find_calibration_value_2(l, calibrator)))
+}
+
+fn strict_sum(values: impl Iterator<Item = Option<u32>>) -> Result<u32, MissingDigits> {
+    let mut sum = 0;
+    let mut lines = Vec::new();
+    for (i, value) in values.enumerate() {
+        match value {
+            Some(value) => sum += value,
+            None => lines.push(i + 1),
+        }
+    }
+    match lines.is_empty() {
+        true => Ok(sum),
+        false => Err(MissingDigits { lines }),
+    }
+}
+
+/// Lines of a calibration document without a calibration value.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub struct MissingDigits {
+    /// 1-based line numbers.
+    pub lines: Vec<usize>,
+}
+
+impl fmt::Display for MissingDigits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.lines.as_slice() {
+            [line] => write!(f, "no digit on line {line}"),
+            lines => write!(
+                f,
+                "no digit on {} lines: {}",
+                lines.len(),
+                lines
+                    .iter()
+                    .map(|l| l.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+impl From<MissingDigits> for Error {
+    fn from(e: MissingDigits) -> Self {
+        Error::Solve(Box::new(e))
+    }
+}
+
+pub fn find_calibration_value_1(s: &str) -> Option<u32> {
+    let first_digit = s.chars().find(char::is_ascii_digit)?;
+    let last_digit = s.chars().rev().find(char::is_ascii_digit)?;
+    Some(format!("{first_digit}{last_digit}").parse::<u32>().unwrap())
+}
+
+/// Like [`find_calibration_value_1`], but with decimal digits of any script,
+/// e.g. fullwidth `３` or Arabic-Indic `٣`.
+pub fn find_calibration_value_1_unicode(s: &str) -> Option<u32> {
+    UnicodeDigits.calibrate(s).map(|c| c.value)
+}
+
+/// The calibration value of a line, with digit words as found by
+/// `calibrator`, usually a [`DigitLexicon`].
+pub fn find_calibration_value_2<C: Calibrator + ?Sized>(s: &str, calibrator: &C) -> Option<u32> {
+    calibrator.calibrate(s).map(|c| c.value)
+}
+
+/// Finds the tokens making up a line's calibration value.
+pub trait Calibrator {
+    fn calibrate(&self, line: &str) -> Option<Calibration>;
+}
+
+/// The first and last tokens of a line, with byte offsets into the line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Calibration {
+    pub first: Match,
+    pub last: Match,
+    pub value: u32,
+}
+
+impl Calibrator for Scanner {
+    fn calibrate(&self, line: &str) -> Option<Calibration> {
+        let (first, last) = self.first_and_last(line)?;
+        Some(Calibration {
+            first,
+            last,
+            value: first.value * 10 + last.value,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_works_1() {
+        assert_eq!(Some(12), find_calibration_value_1("1abc2"));
+        assert_eq!(Some(38), find_calibration_value_1("pqr3stu8vwx"));
+        assert_eq!(Some(15), find_calibration_value_1("a1b2c3d4e5f"));
+        assert_eq!(Some(77), find_calibration_value_1("treb7uchet"));
+        assert_eq!(None, find_calibration_value_1("string-with-no-numbers"));
+    }
+
+    #[test]
+    fn it_lists_lines_without_digits_in_strict_mode() {
+        let doc = "1abc2\nno digits\ntwo\n\ntreb7uchet";
+        assert_eq!(89, sum_calibration_values_1(doc));
+        let e = sum_calibration_values_1_strict(doc).unwrap_err();
+        assert_eq!(vec![2, 3, 4], e.lines);
+        assert_eq!("no digit on 3 lines: 2, 3, 4", e.to_string());
+
+        let e = sum_calibration_values_2_strict(doc, DigitLexicon::english()).unwrap_err();
+        assert_eq!("no digit on 2 lines: 2, 4", e.to_string());
+        assert!(matches!(Error::from(e), Error::Solve(_)));
+        assert_eq!(
+            Ok(111),
+            sum_calibration_values_2_strict("1abc2\ntwo\ntreb7uchet", DigitLexicon::english())
+        );
+    }
+
+    #[test]
+    fn it_works_2() {
+        assert_eq!(
+            Some(29),
+            find_calibration_value_2("two1nine", DigitLexicon::english())
+        );
+        assert_eq!(
+            Some(83),
+            find_calibration_value_2("eightwothree", DigitLexicon::english())
+        );
+        assert_eq!(
+            Some(13),
+            find_calibration_value_2("abcone2threexyz", DigitLexicon::english())
+        );
+        assert_eq!(
+            Some(24),
+            find_calibration_value_2("xtwone3four", DigitLexicon::english())
+        );
+        assert_eq!(
+            Some(42),
+            find_calibration_value_2("4nineeightseven2", DigitLexicon::english())
+        );
+        assert_eq!(
+            Some(14),
+            find_calibration_value_2("zoneight234", DigitLexicon::english())
+        );
+        assert_eq!(
+            Some(76),
+            find_calibration_value_2("7pqrstsixteen", DigitLexicon::english())
+        );
+        assert_eq!(
+            Some(82),
+            find_calibration_value_2("eightwo", DigitLexicon::english())
+        );
+        assert_eq!(
+            None,
+            find_calibration_value_2("string-with-no-numbers", DigitLexicon::english())
+        );
+    }
+
+    #[test]
+    fn it_finds_zero_digits_2() {
+        assert_eq!(
+            Some(5),
+            find_calibration_value_2("0abc5", DigitLexicon::english())
+        );
+        assert_eq!(
+            Some(50),
+            find_calibration_value_2("fiveabc0", DigitLexicon::english())
+        );
+        assert_eq!(
+            Some(0),
+            find_calibration_value_2("x0y", DigitLexicon::english())
+        );
+        assert_eq!(
+            Some(90),
+            find_calibration_value_2("nine0", DigitLexicon::english())
+        );
+    }
+}
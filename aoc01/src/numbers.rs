@@ -0,0 +1,287 @@
+//! English number words beyond single digits: "zero", the teens, the tens,
+//! compounds like "twenty-one" and hundreds like "one hundred and five".
+
+use std::{fmt, str::FromStr};
+
+use aoc_common::Error;
+use once_cell::sync::Lazy;
+
+use crate::{
+    scanner::{self, Match, Pattern, Scanner, TokenKind},
+    Calibration, Calibrator,
+};
+
+const WORDS: &[(&str, u32)] = &[
+    ("zero", 0),
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+    ("ten", 10),
+    ("eleven", 11),
+    ("twelve", 12),
+    ("thirteen", 13),
+    ("fourteen", 14),
+    ("fifteen", 15),
+    ("sixteen", 16),
+    ("seventeen", 17),
+    ("eighteen", 18),
+    ("nineteen", 19),
+    ("twenty", 20),
+    ("thirty", 30),
+    ("forty", 40),
+    ("fifty", 50),
+    ("sixty", 60),
+    ("seventy", 70),
+    ("eighty", 80),
+    ("ninety", 90),
+    ("hundred", HUNDRED),
+];
+const HUNDRED: u32 = 100;
+
+static SCANNER: Lazy<Scanner> = Lazy::new(|| {
+    let words = WORDS
+        .iter()
+        .map(|&(w, n)| Pattern::new(w, n, TokenKind::Word));
+    Scanner::new(scanner::digit_patterns().chain(words))
+});
+
+/// What a line's first and last numbers contribute to its calibration value.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NumberPolicy {
+    /// The first digit of the first number and the last digit of the last, so
+    /// "twenty-one and one hundred" is 20.
+    #[default]
+    Digits,
+    /// The first and last numbers written one after the other, so
+    /// "twenty-one and one hundred" is 21100.
+    WholeNumbers,
+}
+
+impl fmt::Display for NumberPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NumberPolicy::Digits => write!(f, "digits"),
+            NumberPolicy::WholeNumbers => write!(f, "whole"),
+        }
+    }
+}
+
+impl FromStr for NumberPolicy {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "digits" => Ok(NumberPolicy::Digits),
+            "whole" => Ok(NumberPolicy::WholeNumbers),
+            _ => Err(Error::format(format!(
+                "expected 'digits' or 'whole', got '{s}'"
+            ))),
+        }
+    }
+}
+
+/// Finds numbers from zero to 999 written in English words, as well as the
+/// ASCII digits.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct NumberWords {
+    pub policy: NumberPolicy,
+}
+
+impl NumberWords {
+    pub fn new(policy: NumberPolicy) -> Self {
+        NumberWords { policy }
+    }
+
+    /// Every number in `line`, including the parts of compounds and
+    /// overlapping words, so "twenty-one" yields 20, 21 and 1.
+    pub fn numbers(&self, line: &str) -> Vec<Match> {
+        let atoms = SCANNER.find_iter(line).collect::<Vec<_>>();
+        let mut numbers = atoms
+            .iter()
+            .filter(|a| a.kind == TokenKind::Digit || a.value == 0)
+            .copied()
+            .collect::<Vec<_>>();
+
+        let mut starts = atoms
+            .iter()
+            .filter(|a| a.kind == TokenKind::Word)
+            .map(|a| a.start)
+            .collect::<Vec<_>>();
+        starts.sort_unstable();
+        starts.dedup();
+        for start in starts {
+            let small = below_hundred(line, &atoms, start);
+            for &unit in small.iter().filter(|n| n.value < 10) {
+                for hundreds in words_after(line, &atoms, unit, &[" "])
+                    .filter(|h| h.value == HUNDRED)
+                    .map(|h| join(unit, h, unit.value * HUNDRED))
+                {
+                    numbers.push(hundreds);
+                    for pos in after(line, hundreds, &[" and ", " "]) {
+                        numbers.extend(
+                            below_hundred(line, &atoms, pos)
+                                .into_iter()
+                                .map(|rest| join(hundreds, rest, hundreds.value + rest.value)),
+                        );
+                    }
+                }
+            }
+            numbers.extend(small);
+        }
+        numbers
+    }
+}
+
+/// Word numbers from 1 to 99 starting at byte `pos`.
+fn below_hundred(line: &str, atoms: &[Match], pos: usize) -> Vec<Match> {
+    let mut numbers = atoms
+        .iter()
+        .filter(|a| a.start == pos && a.kind == TokenKind::Word && (1..HUNDRED).contains(&a.value))
+        .copied()
+        .collect::<Vec<_>>();
+    let compounds = numbers
+        .iter()
+        .filter(|tens| tens.value >= 20 && tens.value % 10 == 0)
+        .flat_map(|&tens| {
+            words_after(line, atoms, tens, &["-", " "])
+                .filter(|unit| (1..10).contains(&unit.value))
+                .map(move |unit| join(tens, unit, tens.value + unit.value))
+        })
+        .collect::<Vec<_>>();
+    numbers.extend(compounds);
+    numbers
+}
+
+/// Positions right after `m` followed by one of `separators`.
+fn after<'a>(line: &'a str, m: Match, separators: &'a [&str]) -> impl Iterator<Item = usize> + 'a {
+    separators
+        .iter()
+        .filter(move |sep| line[m.end..].starts_with(*sep))
+        .map(move |sep| m.end + sep.len())
+}
+
+/// Words following `m` after one of `separators`.
+fn words_after<'a>(
+    line: &'a str,
+    atoms: &'a [Match],
+    m: Match,
+    separators: &'a [&str],
+) -> impl Iterator<Item = Match> + 'a {
+    after(line, m, separators).flat_map(move |pos| {
+        atoms
+            .iter()
+            .filter(move |a| a.start == pos && a.kind == TokenKind::Word)
+            .copied()
+    })
+}
+
+fn join(first: Match, last: Match, value: u32) -> Match {
+    Match {
+        start: first.start,
+        end: last.end,
+        value,
+        kind: TokenKind::Word,
+    }
+}
+
+impl Calibrator for NumberWords {
+    /// The first number is the one starting first and the last the one ending
+    /// last, the longest one on ties, so compounds are taken whole.
+    fn calibrate(&self, line: &str) -> Option<Calibration> {
+        let numbers = self.numbers(line);
+        let first = *numbers
+            .iter()
+            .min_by_key(|m| (m.start, std::cmp::Reverse(m.end)))?;
+        let last = *numbers
+            .iter()
+            .max_by_key(|m| (m.end, std::cmp::Reverse(m.start)))?;
+        let value = match self.policy {
+            NumberPolicy::Digits => leading_digit(first.value) * 10 + last.value % 10,
+            NumberPolicy::WholeNumbers => first.value * 10u32.pow(digits(last.value)) + last.value,
+        };
+        Some(Calibration { first, last, value })
+    }
+}
+
+fn digits(n: u32) -> u32 {
+    n.checked_ilog10().unwrap_or(0) + 1
+}
+
+fn leading_digit(n: u32) -> u32 {
+    n / 10u32.pow(digits(n) - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(line: &str) -> Vec<u32> {
+        let mut values = NumberWords::default()
+            .numbers(line)
+            .into_iter()
+            .map(|m| m.value)
+            .collect::<Vec<_>>();
+        values.sort();
+        values
+    }
+
+    fn calibrate(policy: NumberPolicy, line: &str) -> Option<u32> {
+        NumberWords::new(policy).calibrate(line).map(|c| c.value)
+    }
+
+    #[test]
+    fn it_recognises_compound_numbers() {
+        assert_eq!(vec![1, 20, 21], values("twenty-one"));
+        assert_eq!(vec![1, 20, 21], values("twenty one"));
+        assert_eq!(vec![1, 100], values("one hundred"));
+        assert_eq!(vec![1, 5, 100, 105], values("one hundred and five"));
+        assert_eq!(
+            vec![9, 9, 9, 90, 99, 900, 909, 990, 999],
+            values("nine hundred ninety-nine")
+        );
+        assert_eq!(vec![0, 7, 17], values("zero seventeen"));
+        assert_eq!(vec![2, 20, 22], values("twenty two"));
+        assert_eq!(vec![2, 20], values("twentyxtwo"));
+    }
+
+    #[test]
+    fn it_applies_the_number_policy() {
+        let line = "twenty-one and one hundred";
+        assert_eq!(Some(20), calibrate(NumberPolicy::Digits, line));
+        assert_eq!(Some(21100), calibrate(NumberPolicy::WholeNumbers, line));
+
+        assert_eq!(Some(17), calibrate(NumberPolicy::Digits, "seventeen"));
+        assert_eq!(
+            Some(1717),
+            calibrate(NumberPolicy::WholeNumbers, "seventeen")
+        );
+        assert_eq!(Some(30), calibrate(NumberPolicy::Digits, "3 zero"));
+        assert_eq!(Some(30), calibrate(NumberPolicy::WholeNumbers, "3 zero"));
+        assert_eq!(Some(82), calibrate(NumberPolicy::Digits, "eightwo"));
+        assert_eq!(None, calibrate(NumberPolicy::Digits, "hundred"));
+    }
+
+    #[test]
+    fn it_agrees_with_single_digits_without_compounds() {
+        let lexicon = crate::lexicon::DigitLexicon::english();
+        for line in [
+            "two1nine",
+            "eightwothree",
+            "abcone2threexyz",
+            "xtwone3four",
+            "zoneight234",
+        ] {
+            assert_eq!(
+                crate::find_calibration_value_2(line, lexicon),
+                calibrate(NumberPolicy::Digits, line),
+                "{line}"
+            );
+        }
+    }
+}
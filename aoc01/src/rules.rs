@@ -0,0 +1,267 @@
+//! Ways of turning the digits of a line into its calibration value, beyond
+//! the first digit followed by the last.
+
+use std::{cmp::Reverse, fmt, str::FromStr};
+
+use aoc_common::{Error, Result};
+
+use crate::{
+    scanner::{Match, Scanner},
+    MissingDigits,
+};
+
+/// How a line's digits, as found by a [`Scanner`], make up its calibration
+/// value. Digits are taken in the order they end, so "eightwo" has the
+/// digits 8 and 2.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CalibrationRule {
+    /// The first digit followed by the last, as in the puzzle and
+    /// [`find_calibration_value_2`](crate::find_calibration_value_2).
+    #[default]
+    FirstLast,
+    /// Every digit of the line, written one after the other.
+    Concatenated,
+    /// The n-th digit from the start followed by the n-th from the end, so
+    /// `Nth(1)` is the same as `FirstLast`. Lines with fewer than n digits
+    /// have no value, which [`strict_sum`](CalibrationRule::strict_sum)
+    /// reports as [`TooFewDigits`] rather than as missing digits.
+    Nth(usize),
+    /// The sum of every digit of the line.
+    Sum,
+}
+
+impl CalibrationRule {
+    /// The calibration value of `line`, or `None` if it has no digit.
+    pub fn apply(self, scanner: &Scanner, line: &str) -> Result<Option<u64>> {
+        let digits = scanner.find_iter(line).collect::<Vec<_>>();
+        if digits.is_empty() {
+            return Ok(None);
+        }
+        let value = match self {
+            CalibrationRule::FirstLast => nth(digits, 1),
+            CalibrationRule::Nth(n) => nth(digits, n),
+            CalibrationRule::Concatenated => Some(digits.iter().try_fold(0u64, |value, m| {
+                value
+                    .checked_mul(10)
+                    .and_then(|v| v.checked_add(u64::from(m.value)))
+                    .ok_or(Error::Overflow("concatenating calibration digits"))
+            })?),
+            CalibrationRule::Sum => Some(digits.iter().map(|m| u64::from(m.value)).sum()),
+        };
+        Ok(value)
+    }
+
+    /// Sum of the calibration values of `document`'s lines, skipping the ones
+    /// without any digit.
+    pub fn sum(self, scanner: &Scanner, document: &str) -> Result<u64> {
+        self.values(scanner, document)
+            .try_fold(0u64, |sum, (_, value)| checked_add(sum, value?))
+    }
+
+    /// Like [`sum`](CalibrationRule::sum), but fails with [`MissingDigits`]
+    /// if any line has no digit, or else with [`TooFewDigits`] if any line
+    /// has fewer than `Nth` needs.
+    pub fn strict_sum(self, scanner: &Scanner, document: &str) -> Result<u64> {
+        let mut sum = 0;
+        let mut missing = Vec::new();
+        let mut too_few = Vec::new();
+        for (i, line) in document.lines().enumerate() {
+            match self.apply(scanner, line)? {
+                Some(value) => sum = checked_add(sum, Some(value))?,
+                None if scanner.find_iter(line).next().is_none() => missing.push(i + 1),
+                None => too_few.push(i + 1),
+            }
+        }
+        match (self, missing.is_empty(), too_few.is_empty()) {
+            (_, false, _) => Err(MissingDigits { lines: missing }.into()),
+            (CalibrationRule::Nth(n), true, false) => {
+                Err(TooFewDigits { n, lines: too_few }.into())
+            }
+            _ => Ok(sum),
+        }
+    }
+
+    fn values<'a>(
+        self,
+        scanner: &'a Scanner,
+        document: &'a str,
+    ) -> impl Iterator<Item = (usize, Result<Option<u64>>)> + 'a {
+        document
+            .lines()
+            .enumerate()
+            .map(move |(i, line)| (i + 1, self.apply(scanner, line)))
+    }
+}
+
+/// Lines of a calibration document with digits, but fewer than
+/// [`CalibrationRule::Nth`] needs.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub struct TooFewDigits {
+    pub n: usize,
+    /// 1-based line numbers.
+    pub lines: Vec<usize>,
+}
+
+impl fmt::Display for TooFewDigits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let n = self.n;
+        match self.lines.as_slice() {
+            [line] => write!(f, "fewer than {n} digits on line {line}"),
+            lines => write!(
+                f,
+                "fewer than {n} digits on {} lines: {}",
+                lines.len(),
+                lines
+                    .iter()
+                    .map(|l| l.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+impl From<TooFewDigits> for Error {
+    fn from(e: TooFewDigits) -> Self {
+        Error::Solve(Box::new(e))
+    }
+}
+
+/// The n-th of `digits` from the start followed by the n-th from the end,
+/// with `digits` in the order [`Scanner::find_iter`] gives them.
+fn nth(mut digits: Vec<Match>, n: usize) -> Option<u64> {
+    let from_start = digits.get(n.checked_sub(1)?).copied();
+    // Latest start first, longest first among those, the way
+    // `Scanner::first_and_last` picks the last match
+    digits.sort_by_key(|m| Reverse((m.start, m.end)));
+    let from_end = digits.get(n - 1).copied();
+    from_start
+        .zip(from_end)
+        .map(|(first, last)| u64::from(first.value * 10 + last.value))
+}
+
+fn checked_add(sum: u64, value: Option<u64>) -> Result<u64> {
+    sum.checked_add(value.unwrap_or(0))
+        .ok_or(Error::Overflow("summing calibration values"))
+}
+
+impl fmt::Display for CalibrationRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CalibrationRule::FirstLast => write!(f, "first-last"),
+            CalibrationRule::Concatenated => write!(f, "concat"),
+            CalibrationRule::Nth(n) => write!(f, "nth:{n}"),
+            CalibrationRule::Sum => write!(f, "sum"),
+        }
+    }
+}
+
+impl FromStr for CalibrationRule {
+    type Err = Error;
+
+    /// Parses `first-last`, `concat`, `sum` or `nth:N` with N from 1.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "first-last" => Ok(CalibrationRule::FirstLast),
+            "concat" => Ok(CalibrationRule::Concatenated),
+            "sum" => Ok(CalibrationRule::Sum),
+            _ => match s.strip_prefix("nth:").map(str::parse) {
+                Some(Ok(n)) if n > 0 => Ok(CalibrationRule::Nth(n)),
+                _ => Err(Error::format(format!(
+                    "expected 'first-last', 'concat', 'sum' or 'nth:N' with N from 1, got '{s}'"
+                ))),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{find_calibration_value_2, lexicon::DigitLexicon};
+
+    fn apply(rule: CalibrationRule, line: &str) -> Option<u64> {
+        rule.apply(DigitLexicon::english().scanner(), line).unwrap()
+    }
+
+    #[test]
+    fn it_applies_each_rule() {
+        let line = "xtwone3four";
+        assert_eq!(Some(24), apply(CalibrationRule::FirstLast, line));
+        assert_eq!(Some(2134), apply(CalibrationRule::Concatenated, line));
+        assert_eq!(Some(13), apply(CalibrationRule::Nth(2), line));
+        assert_eq!(None, apply(CalibrationRule::Nth(5), line));
+        assert_eq!(Some(10), apply(CalibrationRule::Sum, line));
+        assert_eq!(Some(82), apply(CalibrationRule::Concatenated, "eightwo"));
+        assert_eq!(Some(0), apply(CalibrationRule::Sum, "0"));
+        for rule in [
+            CalibrationRule::FirstLast,
+            CalibrationRule::Concatenated,
+            CalibrationRule::Nth(1),
+            CalibrationRule::Sum,
+        ] {
+            assert_eq!(None, apply(rule, "abc"), "{rule}");
+        }
+    }
+
+    #[test]
+    fn it_agrees_with_part_2_on_first_and_last() {
+        let lexicon = DigitLexicon::english();
+        for line in ["two1nine", "eightwothree", "zoneight234", "7pqrstsixteen"] {
+            let expected = find_calibration_value_2(line, lexicon).map(u64::from);
+            assert_eq!(expected, apply(CalibrationRule::FirstLast, line));
+            assert_eq!(expected, apply(CalibrationRule::Nth(1), line));
+        }
+    }
+
+    #[test]
+    fn it_sums_documents() {
+        let scanner = DigitLexicon::english().scanner();
+        let doc = "one2\nabc\n34";
+        assert_eq!(
+            Ok(46),
+            CalibrationRule::Concatenated
+                .sum(scanner, doc)
+                .map_err(|e| e.to_string())
+        );
+        let e = CalibrationRule::Sum.strict_sum(scanner, doc).unwrap_err();
+        assert_eq!("no digit on line 2", e.to_string());
+        let e = CalibrationRule::Concatenated
+            .sum(scanner, &"9".repeat(20))
+            .unwrap_err();
+        assert!(matches!(e, Error::Overflow(_)));
+    }
+
+    #[test]
+    fn it_tells_lines_without_digits_from_lines_with_too_few() {
+        let scanner = DigitLexicon::english().scanner();
+        let e = CalibrationRule::Nth(2)
+            .strict_sum(scanner, "12\nab3c\n45")
+            .unwrap_err();
+        assert_eq!("fewer than 2 digits on line 2", e.to_string());
+        let e = CalibrationRule::Nth(2)
+            .strict_sum(scanner, "12\nab3c\nabc")
+            .unwrap_err();
+        assert_eq!("no digit on line 3", e.to_string());
+        assert_eq!(
+            Ok(65),
+            CalibrationRule::Nth(2)
+                .strict_sum(scanner, "12\n345")
+                .map_err(|e| e.to_string())
+        );
+    }
+
+    #[test]
+    fn it_parses_rules() {
+        for rule in [
+            CalibrationRule::FirstLast,
+            CalibrationRule::Concatenated,
+            CalibrationRule::Nth(3),
+            CalibrationRule::Sum,
+        ] {
+            assert_eq!(rule, rule.to_string().parse().unwrap());
+        }
+        assert!("nth:0".parse::<CalibrationRule>().is_err());
+        assert!("last".parse::<CalibrationRule>().is_err());
+    }
+}
@@ -0,0 +1,242 @@
+//! Aho–Corasick automaton finding every digit and digit word of a line in a
+//! single pass over its bytes.
+
+use once_cell::sync::Lazy;
+
+const ALPHABET: usize = 256;
+const ROOT: u32 = 0;
+const NONE: u32 = u32::MAX;
+
+static DIGITS: Lazy<Scanner> = Lazy::new(|| Scanner::new(digit_patterns()));
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenKind {
+    Digit,
+    Word,
+    /// A word with typos, found by [`FuzzyMatcher`](crate::fuzzy::FuzzyMatcher).
+    Fuzzy,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern {
+    pub text: String,
+    pub value: u32,
+    pub kind: TokenKind,
+}
+
+impl Pattern {
+    pub fn new(text: impl Into<String>, value: u32, kind: TokenKind) -> Self {
+        Pattern {
+            text: text.into(),
+            value,
+            kind,
+        }
+    }
+}
+
+/// A pattern found in a haystack, at byte offsets `start..end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    pub start: usize,
+    pub end: usize,
+    pub value: u32,
+    pub kind: TokenKind,
+}
+
+/// The ASCII digits `0` to `9`.
+pub fn digit_patterns() -> impl Iterator<Item = Pattern> {
+    (0..=9).map(|d| Pattern::new(d.to_string(), d, TokenKind::Digit))
+}
+
+#[derive(Debug, Clone)]
+pub struct Scanner {
+    /// `next[state * ALPHABET + byte]`, with failure transitions folded in so
+    /// every byte costs exactly one lookup
+    next: Vec<u32>,
+    /// Patterns ending in each state, longest first, including the ones
+    /// reached through failure links
+    outputs: Vec<Vec<usize>>,
+    patterns: Vec<Pattern>,
+}
+
+impl Scanner {
+    /// Builds the automaton. Empty patterns never match.
+    pub fn new(patterns: impl IntoIterator<Item = Pattern>) -> Self {
+        let patterns = patterns
+            .into_iter()
+            .filter(|p| !p.text.is_empty())
+            .collect::<Vec<_>>();
+        let mut scanner = Scanner {
+            next: vec![NONE; ALPHABET],
+            outputs: vec![Vec::new()],
+            patterns,
+        };
+
+        for (i, pattern) in scanner.patterns.iter().enumerate() {
+            let mut state = ROOT;
+            for &b in pattern.text.as_bytes() {
+                let ix = state as usize * ALPHABET + b as usize;
+                if scanner.next[ix] == NONE {
+                    scanner.next[ix] = scanner.outputs.len() as u32;
+                    scanner.next.extend([NONE; ALPHABET]);
+                    scanner.outputs.push(Vec::new());
+                }
+                state = scanner.next[ix];
+            }
+            scanner.outputs[state as usize].push(i);
+        }
+
+        // Breadth first, so a state's failure state is complete before it
+        let mut fail = vec![ROOT; scanner.outputs.len()];
+        let mut queue = std::collections::VecDeque::new();
+        for b in 0..ALPHABET {
+            match scanner.next[b] {
+                NONE => scanner.next[b] = ROOT,
+                child => queue.push_back(child),
+            }
+        }
+        while let Some(state) = queue.pop_front() {
+            let outputs = scanner.outputs[fail[state as usize] as usize].clone();
+            scanner.outputs[state as usize].extend(outputs);
+            for b in 0..ALPHABET {
+                let ix = state as usize * ALPHABET + b;
+                let fallback = scanner.next[fail[state as usize] as usize * ALPHABET + b];
+                match scanner.next[ix] {
+                    NONE => scanner.next[ix] = fallback,
+                    child => {
+                        fail[child as usize] = fallback;
+                        queue.push_back(child);
+                    }
+                }
+            }
+        }
+        scanner
+    }
+
+    /// Scanner for the ASCII digits.
+    pub fn digits() -> &'static Scanner {
+        &DIGITS
+    }
+
+    pub fn patterns(&self) -> &[Pattern] {
+        &self.patterns
+    }
+
+    /// Every match in `haystack`, overlapping ones included, ordered by where
+    /// they end and longest first among those ending at the same byte.
+    pub fn find_iter<'s, 'h>(&'s self, haystack: &'h str) -> Matches<'s, 'h> {
+        Matches {
+            scanner: self,
+            haystack: haystack.as_bytes(),
+            pos: 0,
+            state: ROOT,
+            pending: &[],
+        }
+    }
+
+    /// The match ending first and the match starting last, in one pass.
+    /// Ties go to the longer match, so in "eightwo" these are "eight" and
+    /// "two".
+    pub fn first_and_last(&self, haystack: &str) -> Option<(Match, Match)> {
+        let mut matches = self.find_iter(haystack);
+        let first = matches.next()?;
+        let last = matches.fold(
+            first,
+            |last, m| if m.start >= last.start { m } else { last },
+        );
+        Some((first, last))
+    }
+}
+
+pub struct Matches<'s, 'h> {
+    scanner: &'s Scanner,
+    haystack: &'h [u8],
+    pos: usize,
+    state: u32,
+    pending: &'s [usize],
+}
+
+impl Iterator for Matches<'_, '_> {
+    type Item = Match;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((&i, rest)) = self.pending.split_first() {
+                self.pending = rest;
+                let pattern = &self.scanner.patterns[i];
+                return Some(Match {
+                    start: self.pos - pattern.text.len(),
+                    end: self.pos,
+                    value: pattern.value,
+                    kind: pattern.kind,
+                });
+            }
+
+            let &b = self.haystack.get(self.pos)?;
+            self.state = self.scanner.next[self.state as usize * ALPHABET + b as usize];
+            self.pos += 1;
+            self.pending = &self.scanner.outputs[self.state as usize];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexicon::{DigitLexicon, Language};
+
+    fn values(scanner: &Scanner, s: &str) -> Vec<(usize, u32)> {
+        scanner.find_iter(s).map(|m| (m.start, m.value)).collect()
+    }
+
+    #[test]
+    fn it_finds_overlapping_words() {
+        let scanner = DigitLexicon::english().scanner();
+        assert_eq!(vec![(0, 8), (4, 2)], values(scanner, "eightwo"));
+        assert_eq!(
+            vec![(1, 2), (3, 1), (6, 3), (7, 4)],
+            values(scanner, "xtwone34")
+        );
+        assert_eq!(vec![(3, 9)], values(scanner, "ninnine"));
+    }
+
+    #[test]
+    fn it_finds_first_and_last_matches() {
+        let scanner = DigitLexicon::english().scanner();
+        let (first, last) = scanner.first_and_last("zoneight234").unwrap();
+        assert_eq!(
+            (1, 4, 1, TokenKind::Word),
+            (first.start, first.end, first.value, first.kind)
+        );
+        assert_eq!(
+            (10, 11, 4, TokenKind::Digit),
+            (last.start, last.end, last.value, last.kind)
+        );
+        assert_eq!(None, scanner.first_and_last("abcdef"));
+        assert_eq!(None, Scanner::digits().first_and_last("one"));
+    }
+
+    #[test]
+    fn it_prefers_longer_matches_on_ties() {
+        let scanner = Scanner::new([
+            Pattern::new("twenty", 20, TokenKind::Word),
+            Pattern::new("twenty-one", 21, TokenKind::Word),
+            Pattern::new("one", 1, TokenKind::Word),
+        ]);
+        assert_eq!(
+            vec![(0, 20), (0, 21), (7, 1)],
+            values(&scanner, "twenty-one")
+        );
+        let (first, last) = scanner.first_and_last("twenty-one").unwrap();
+        assert_eq!((20, 1), (first.value, last.value));
+        let (_, last) = scanner.first_and_last("one twenty-one").unwrap();
+        assert_eq!(1, last.value);
+    }
+
+    #[test]
+    fn it_matches_multibyte_patterns_on_char_boundaries() {
+        let scanner = DigitLexicon::builtin(Language::German).scanner();
+        let m = scanner.find_iter("ääfünfß").next().unwrap();
+        assert_eq!((4, 9, 5), (m.start, m.end, m.value));
+    }
+}
@@ -0,0 +1,179 @@
+//! Calibration sums over documents too large to read into memory at once.
+
+use std::{
+    io::{self, BufRead},
+    ops::AddAssign,
+    str,
+    sync::{mpsc, Arc, Mutex},
+    thread,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StreamOptions {
+    /// Lines are read in chunks of at least this many bytes, cut after a
+    /// newline. A chunk only grows beyond this for a line longer than it.
+    pub chunk_size: usize,
+    /// Threads summing chunks in parallel. At most twice as many chunks as
+    /// threads are in memory at once.
+    pub threads: usize,
+}
+
+impl Default for StreamOptions {
+    fn default() -> Self {
+        StreamOptions {
+            chunk_size: 1 << 20,
+            threads: thread::available_parallelism().map_or(1, |n| n.get()),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Totals {
+    pub sum: u64,
+    pub lines: u64,
+    /// Lines without a calibration value.
+    pub skipped: u64,
+}
+
+impl AddAssign for Totals {
+    fn add_assign(&mut self, rhs: Self) {
+        self.sum += rhs.sum;
+        self.lines += rhs.lines;
+        self.skipped += rhs.skipped;
+    }
+}
+
+/// Sums the calibration values `find` gives every line of `reader`, e.g.
+/// [`find_calibration_value_1`](crate::find_calibration_value_1). Lines are
+/// split like [`str::lines`] does, and must be UTF-8.
+pub fn sum_calibration_values<R, F>(
+    mut reader: R,
+    options: StreamOptions,
+    find: F,
+) -> io::Result<Totals>
+where
+    R: BufRead,
+    F: Fn(&str) -> Option<u32> + Sync,
+{
+    let threads = options.threads.max(1);
+    let (chunks, receiver) = mpsc::sync_channel::<Vec<u8>>(threads);
+    // Only the workers hold the receiver, so reading stops once they're all gone
+    let receiver = Arc::new(Mutex::new(receiver));
+
+    thread::scope(|scope| {
+        let workers = (0..threads)
+            .map(|_| {
+                let receiver = Arc::clone(&receiver);
+                let find = &find;
+                scope.spawn(move || -> io::Result<Totals> {
+                    let mut totals = Totals::default();
+                    loop {
+                        let Ok(chunk) = receiver.lock().unwrap().recv() else {
+                            return Ok(totals);
+                        };
+                        totals += sum_chunk(&chunk, find)?;
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        drop(receiver);
+
+        let read = (|| loop {
+            let chunk = read_chunk(&mut reader, options.chunk_size)?;
+            if chunk.is_empty() || chunks.send(chunk).is_err() {
+                return Ok(());
+            }
+        })();
+        drop(chunks);
+
+        let mut totals = Totals::default();
+        for worker in workers {
+            totals += worker.join().expect("calibration workers don't panic")?;
+        }
+        read.map(|()| totals)
+    })
+}
+
+fn read_chunk(reader: &mut impl BufRead, size: usize) -> io::Result<Vec<u8>> {
+    let mut chunk = Vec::new();
+    while chunk.len() < size.max(1) {
+        if reader.read_until(b'\n', &mut chunk)? == 0 {
+            break;
+        }
+    }
+    Ok(chunk)
+}
+
+fn sum_chunk(chunk: &[u8], find: impl Fn(&str) -> Option<u32>) -> io::Result<Totals> {
+    let text = str::from_utf8(chunk).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let mut totals = Totals::default();
+    for line in text.lines() {
+        totals.lines += 1;
+        match find(line) {
+            Some(value) => totals.sum += u64::from(value),
+            None => totals.skipped += 1,
+        }
+    }
+    Ok(totals)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        find_calibration_value_1, find_calibration_value_2, lexicon::DigitLexicon,
+        sum_calibration_values_1, sum_calibration_values_2,
+    };
+
+    const DOC: &str = "two1nine\neightwothree\r\nabcone2threexyz\n\nxtwone3four\n4nineeightseven2\nzoneight234\n7pqrstsixteen\nno digits\n";
+
+    #[test]
+    fn it_matches_the_in_memory_sums() {
+        let doc = DOC.repeat(50);
+        let lexicon = DigitLexicon::english();
+        for (chunk_size, threads) in [(1, 1), (7, 3), (64, 4), (1 << 20, 2)] {
+            let options = StreamOptions {
+                chunk_size,
+                threads,
+            };
+            let totals =
+                sum_calibration_values(doc.as_bytes(), options, find_calibration_value_1).unwrap();
+            assert_eq!(u64::from(sum_calibration_values_1(&doc)), totals.sum);
+            assert_eq!(450, totals.lines);
+            assert_eq!(150, totals.skipped);
+
+            let totals = sum_calibration_values(doc.as_bytes(), options, |l| {
+                find_calibration_value_2(l, lexicon)
+            })
+            .unwrap();
+            assert_eq!(
+                u64::from(sum_calibration_values_2(&doc, lexicon)),
+                totals.sum
+            );
+            assert_eq!(100, totals.skipped);
+        }
+    }
+
+    #[test]
+    fn it_handles_a_last_line_without_newline() {
+        let totals = sum_calibration_values(
+            "1a\n2b".as_bytes(),
+            StreamOptions::default(),
+            find_calibration_value_1,
+        )
+        .unwrap();
+        assert_eq!((33, 2), (totals.sum, totals.lines));
+    }
+
+    #[test]
+    fn it_rejects_invalid_utf8() {
+        let options = StreamOptions {
+            chunk_size: 4,
+            threads: 2,
+        };
+        let doc = b"12\n\xff3\n45\n".repeat(10);
+        let e =
+            sum_calibration_values(doc.as_slice(), options, find_calibration_value_1).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidData, e.kind());
+    }
+}
@@ -0,0 +1,187 @@
+//! Decimal digits from every script, for documents that don't stick to ASCII.
+
+use crate::{
+    scanner::{Match, TokenKind},
+    Calibration, Calibrator,
+};
+
+/// The zero of every run of ten decimal digits (general category `Nd`) as of
+/// Unicode 17.0.0, in order. Unicode only assigns `Nd` in such runs, from zero
+/// to nine, though some runs directly follow one another.
+const ZEROS: &[char] = &[
+    '\u{0030}',
+    '\u{0660}',
+    '\u{06F0}',
+    '\u{07C0}',
+    '\u{0966}',
+    '\u{09E6}',
+    '\u{0A66}',
+    '\u{0AE6}',
+    '\u{0B66}',
+    '\u{0BE6}',
+    '\u{0C66}',
+    '\u{0CE6}',
+    '\u{0D66}',
+    '\u{0DE6}',
+    '\u{0E50}',
+    '\u{0ED0}',
+    '\u{0F20}',
+    '\u{1040}',
+    '\u{1090}',
+    '\u{17E0}',
+    '\u{1810}',
+    '\u{1946}',
+    '\u{19D0}',
+    '\u{1A80}',
+    '\u{1A90}',
+    '\u{1B50}',
+    '\u{1BB0}',
+    '\u{1C40}',
+    '\u{1C50}',
+    '\u{A620}',
+    '\u{A8D0}',
+    '\u{A900}',
+    '\u{A9D0}',
+    '\u{A9F0}',
+    '\u{AA50}',
+    '\u{ABF0}',
+    '\u{FF10}',
+    '\u{104A0}',
+    '\u{10D30}',
+    '\u{10D40}',
+    '\u{11066}',
+    '\u{110F0}',
+    '\u{11136}',
+    '\u{111D0}',
+    '\u{112F0}',
+    '\u{11450}',
+    '\u{114D0}',
+    '\u{11650}',
+    '\u{116C0}',
+    '\u{116D0}',
+    '\u{116DA}',
+    '\u{11730}',
+    '\u{118E0}',
+    '\u{11950}',
+    '\u{11BF0}',
+    '\u{11C50}',
+    '\u{11D50}',
+    '\u{11DA0}',
+    '\u{11DE0}',
+    '\u{11F50}',
+    '\u{16130}',
+    '\u{16A60}',
+    '\u{16AC0}',
+    '\u{16B50}',
+    '\u{16D70}',
+    '\u{1CCF0}',
+    '\u{1D7CE}',
+    '\u{1D7D8}',
+    '\u{1D7E2}',
+    '\u{1D7EC}',
+    '\u{1D7F6}',
+    '\u{1E140}',
+    '\u{1E2F0}',
+    '\u{1E4F0}',
+    '\u{1E5F1}',
+    '\u{1E950}',
+    '\u{1FBF0}',
+];
+
+/// The value of `c` if it's a decimal digit in any script, like `３` or `٣`.
+pub fn decimal_digit(c: char) -> Option<u32> {
+    let i = ZEROS.partition_point(|&zero| zero <= c).checked_sub(1)?;
+    let value = c as u32 - ZEROS[i] as u32;
+    (value < 10).then_some(value)
+}
+
+/// Finds decimal digits of any script, the first and last making up the
+/// calibration value just like ASCII ones do for part 1.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct UnicodeDigits;
+
+impl Calibrator for UnicodeDigits {
+    fn calibrate(&self, line: &str) -> Option<Calibration> {
+        let digit = |(i, c): (usize, char)| {
+            decimal_digit(c).map(|value| Match {
+                start: i,
+                end: i + c.len_utf8(),
+                value,
+                kind: TokenKind::Digit,
+            })
+        };
+        let first = line.char_indices().find_map(digit)?;
+        let last = line.char_indices().rev().find_map(digit)?;
+        Some(Calibration {
+            first,
+            last,
+            value: first.value * 10 + last.value,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{find_calibration_value_1, find_calibration_value_1_unicode};
+
+    #[test]
+    fn it_reads_digits_of_every_script() {
+        assert_eq!(Some(3), decimal_digit('３'));
+        assert_eq!(Some(3), decimal_digit('٣'));
+        assert_eq!(Some(3), decimal_digit('३'));
+        assert_eq!(Some(0), decimal_digit('0'));
+        assert_eq!(Some(9), decimal_digit('9'));
+        assert_eq!(Some(7), decimal_digit('\u{1D7D5}'));
+        assert_eq!(None, decimal_digit('a'));
+        assert_eq!(None, decimal_digit('/'));
+        assert_eq!(None, decimal_digit('Ⅻ'));
+        assert_eq!(None, decimal_digit('½'));
+        assert_eq!(None, decimal_digit('\u{10FFFF}'));
+    }
+
+    #[test]
+    fn it_reads_digits_added_since_unicode_14() {
+        let digits = [
+            ('\u{11F50}', 0), // Kawi
+            ('\u{11F59}', 9),
+            ('\u{1E4F3}', 3), // Nag Mundari
+            ('\u{10D45}', 5), // Garay
+            ('\u{16139}', 9), // Gurung Khema
+            ('\u{16D71}', 1), // Kirat Rai
+            ('\u{11BF4}', 4), // Sunuwar
+            ('\u{116D2}', 2), // Myanmar Pao
+            ('\u{116DA}', 0), // Myanmar Eastern Pwo Karen
+            ('\u{116E3}', 9),
+            ('\u{1CCF7}', 7), // Outlined
+            ('\u{1E5F1}', 0), // Ol Onal
+            ('\u{1E5FA}', 9),
+            ('\u{11DE6}', 6), // Tolong Siki
+        ];
+        for (c, value) in digits {
+            assert_eq!(Some(value), decimal_digit(c), "{c:?}");
+        }
+        assert_eq!(None, decimal_digit('\u{116E4}'));
+        assert_eq!(None, decimal_digit('\u{1E5F0}'));
+        assert_eq!(None, decimal_digit('\u{1CCFA}'));
+    }
+
+    #[test]
+    fn it_finds_calibration_values_on_mixed_script_lines() {
+        assert_eq!(Some(31), find_calibration_value_1_unicode("ab３cd٤x1"));
+        assert_eq!(Some(74), find_calibration_value_1_unicode("٧ and ४"));
+        assert_eq!(Some(55), find_calibration_value_1_unicode("only ５ here"));
+        assert_eq!(Some(12), find_calibration_value_1_unicode("1abc2"));
+        assert_eq!(None, find_calibration_value_1_unicode("twelve Ⅻ"));
+
+        assert_eq!(Some(11), find_calibration_value_1("ab３cd٤x1"));
+        assert_eq!(None, find_calibration_value_1("only ５ here"));
+    }
+
+    #[test]
+    fn it_reports_byte_offsets_of_multibyte_digits() {
+        let c = UnicodeDigits.calibrate("x٣y３").unwrap();
+        assert_eq!((1, 3), (c.first.start, c.first.end));
+        assert_eq!((4, 7), (c.last.start, c.last.end));
+    }
+}
@@ -0,0 +1,334 @@
+//! Rules for which games are valid, combined from a small expression
+//! language like `red<=12 && total<=39`.
+
+use std::{collections::BTreeSet, fmt, num::ParseIntError, str::FromStr};
+
+use aoc_common::{Error, Result};
+
+use crate::{report::Violation, Cubes, Game};
+
+/// A rule every game must follow to be valid.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Constraint {
+    /// `red<=12`: no reveal has more than `max` cubes of `colour`.
+    ColourMax { colour: String, max: u32 },
+    /// `other<=0`: no reveal has more than `max` cubes of any colour without
+    /// a [`ColourMax`](Constraint::ColourMax) of its own.
+    OtherMax(u32),
+    /// `total<=39`: no reveal has more than `max` cubes in all.
+    TotalMax(usize),
+    /// `red/blue<=2`: no reveal has more than `max` times as many cubes of
+    /// `colour` as of `per`.
+    Ratio {
+        colour: String,
+        per: String,
+        max: f64,
+    },
+    /// `has green`: some reveal has cubes of `colour`.
+    Includes(String),
+}
+
+/// Constraints a game must all follow.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConstraintSet {
+    constraints: Vec<Constraint>,
+    /// Colours with a `ColourMax`, which `OtherMax` doesn't apply to
+    limited: BTreeSet<String>,
+}
+
+impl ConstraintSet {
+    pub fn new(constraints: Vec<Constraint>) -> Self {
+        let limited = constraints
+            .iter()
+            .filter_map(|c| match c {
+                Constraint::ColourMax { colour, .. } => Some(colour.clone()),
+                _ => None,
+            })
+            .collect();
+        ConstraintSet {
+            constraints,
+            limited,
+        }
+    }
+
+    /// The puzzle's rules for `bag`: no reveal has more cubes of a colour than
+    /// the bag, none of a colour it lacks, and no more cubes in total.
+    pub fn from_bag(bag: &Cubes) -> Result<Self> {
+        let total = bag
+            .sum()
+            .ok_or(Error::Overflow("counting the cubes in the bag"))?;
+        let mut constraints = bag
+            .iter()
+            .map(|(colour, max)| Constraint::ColourMax {
+                colour: colour.to_owned(),
+                max,
+            })
+            .collect::<Vec<_>>();
+        constraints.push(Constraint::OtherMax(0));
+        constraints.push(Constraint::TotalMax(total));
+        Ok(ConstraintSet::new(constraints))
+    }
+
+    pub fn constraints(&self) -> &[Constraint] {
+        &self.constraints
+    }
+
+    /// Whether `game` follows every constraint.
+    pub fn allows(&self, game: &Game) -> Result<bool> {
+        let includes = self.constraints.iter().all(|constraint| match constraint {
+            Constraint::Includes(colour) => game.reveals().iter().any(|r| r.count(colour) > 0),
+            _ => true,
+        });
+        Ok(includes && self.violations(game)?.is_empty())
+    }
+
+    /// Where the reveals of `game` break a constraint, in order of reveal.
+    /// `has` constraints are about whole games rather than reveals, so only
+    /// [`allows`](Self::allows) checks them.
+    pub fn violations(&self, game: &Game) -> Result<Vec<Violation>> {
+        let mut violations = Vec::new();
+        for (i, reveal) in game.reveals().iter().enumerate() {
+            for constraint in &self.constraints {
+                self.check_reveal(constraint, i + 1, reveal, &mut violations)
+                    .map_err(|action| game.overflow(Some(i), action))?;
+            }
+        }
+        Ok(violations)
+    }
+
+    fn check_reveal(
+        &self,
+        constraint: &Constraint,
+        reveal: usize,
+        cubes: &Cubes,
+        violations: &mut Vec<Violation>,
+    ) -> Result<(), &'static str> {
+        let violation = |colour: Option<&str>, revealed: usize, allowed: usize| {
+            (revealed > allowed).then(|| Violation {
+                reveal,
+                colour: colour.map(str::to_owned),
+                revealed,
+                allowed,
+                excess: revealed - allowed,
+            })
+        };
+        match constraint {
+            Constraint::ColourMax { colour, max } => violations.extend(violation(
+                Some(colour),
+                cubes.count(colour) as usize,
+                *max as usize,
+            )),
+            Constraint::OtherMax(max) => violations.extend(
+                cubes
+                    .iter()
+                    .filter(|(colour, _)| !self.limited.contains(*colour))
+                    .filter_map(|(colour, n)| violation(Some(colour), n as usize, *max as usize)),
+            ),
+            Constraint::TotalMax(max) => {
+                let revealed = cubes.sum().ok_or("counting revealed cubes")?;
+                violations.extend(violation(None, revealed, *max));
+            }
+            Constraint::Ratio { colour, per, max } => {
+                // Counts are whole, so this many of `colour` is the most allowed
+                let allowed = (max * f64::from(cubes.count(per))).floor() as usize;
+                violations.extend(violation(
+                    Some(colour),
+                    cubes.count(colour) as usize,
+                    allowed,
+                ));
+            }
+            Constraint::Includes(_) => {}
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Constraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Constraint::ColourMax { colour, max } => write!(f, "{colour}<={max}"),
+            Constraint::OtherMax(max) => write!(f, "other<={max}"),
+            Constraint::TotalMax(max) => write!(f, "total<={max}"),
+            Constraint::Ratio { colour, per, max } => write!(f, "{colour}/{per}<={max}"),
+            Constraint::Includes(colour) => write!(f, "has {colour}"),
+        }
+    }
+}
+
+/// The constraints joined by `&&`, which parses back to the same set.
+impl fmt::Display for ConstraintSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, constraint) in self.constraints.iter().enumerate() {
+            if i > 0 {
+                write!(f, " && ")?;
+            }
+            write!(f, "{constraint}")?;
+        }
+        Ok(())
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ParseErrorKind>;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseErrorKind {
+    #[error("expected a constraint like 'red<=12', 'total<=39', 'red/blue<=2' or 'has green'")]
+    ExpectedConstraint,
+    #[error("expected a colour made of letters")]
+    InvalidColour,
+    #[error("invalid limit: {0}")]
+    InvalidLimit(ParseIntError),
+    #[error("expected a ratio that is a finite number, at least 0")]
+    InvalidRatio,
+}
+
+impl FromStr for ConstraintSet {
+    type Err = ParseError;
+
+    /// Parses constraints joined by `&&`, e.g. `red<=12 && has green`.
+    /// `total` and `other` are keywords rather than colours.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split("&&")
+            .map(|atom| parse_constraint(s, atom.trim()))
+            .collect::<Result<_, _>>()
+            .map(ConstraintSet::new)
+    }
+}
+
+fn parse_constraint(s: &str, atom: &str) -> Result<Constraint, ParseError> {
+    let colour = |c: &str| {
+        let c = c.trim();
+        match !c.is_empty() && c.chars().all(char::is_alphabetic) {
+            true => Ok(c.to_owned()),
+            false => Err(ParseError::new(ParseErrorKind::InvalidColour, s, c)),
+        }
+    };
+
+    if let Some(c) = atom.strip_prefix("has ") {
+        return Ok(Constraint::Includes(colour(c)?));
+    }
+    let Some((lhs, limit)) = atom.split_once("<=") else {
+        return Err(ParseError::new(ParseErrorKind::ExpectedConstraint, s, atom));
+    };
+    let (lhs, limit) = (lhs.trim(), limit.trim());
+    match lhs.split_once('/') {
+        Some((c, per)) => Ok(Constraint::Ratio {
+            colour: colour(c)?,
+            per: colour(per)?,
+            max: ratio(s, limit)?,
+        }),
+        None if lhs == "total" => Ok(Constraint::TotalMax(int(s, limit)?)),
+        None if lhs == "other" => Ok(Constraint::OtherMax(int(s, limit)?)),
+        None => Ok(Constraint::ColourMax {
+            colour: colour(lhs)?,
+            max: int(s, limit)?,
+        }),
+    }
+}
+
+fn int<T: FromStr<Err = ParseIntError>>(s: &str, limit: &str) -> Result<T, ParseError> {
+    limit
+        .parse()
+        .map_err(|e| ParseError::new(ParseErrorKind::InvalidLimit(e), s, limit))
+}
+
+fn ratio(s: &str, limit: &str) -> Result<f64, ParseError> {
+    match limit.parse::<f64>() {
+        Ok(max) if max.is_finite() && max >= 0.0 => Ok(max),
+        _ => Err(ParseError::new(ParseErrorKind::InvalidRatio, s, limit)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{parse, Span};
+
+    use super::*;
+
+    fn allowed(constraints: &str, games: &str) -> Vec<usize> {
+        let constraints = constraints.parse::<ConstraintSet>().unwrap();
+        parse::each_line::<Game, _>(games)
+            .unwrap()
+            .iter()
+            .filter(|g| constraints.allows(g).unwrap())
+            .map(Game::id)
+            .collect()
+    }
+
+    #[test]
+    fn it_applies_each_constraint() {
+        let games = "Game 1: 3 red, 1 blue
+Game 2: 1 red, 2 blue; 4 green
+Game 3: 5 yellow";
+        assert_eq!(vec![2, 3], allowed("red<=2", games));
+        assert_eq!(vec![1, 2], allowed("red<=5 && blue<=5 && other<=4", games));
+        assert_eq!(vec![1, 2], allowed("total<=4", games));
+        assert_eq!(vec![2, 3], allowed("red/blue<=0.5", games));
+        assert_eq!(vec![1, 2], allowed("red / blue <= 3 && has blue", games));
+        assert_eq!(vec![3], allowed("has yellow", games));
+    }
+
+    #[test]
+    fn it_lists_violations_by_reveal() {
+        let constraints = "red<=3 && other<=3 && red/blue<=1.5 && total<=5 && has green"
+            .parse::<ConstraintSet>()
+            .unwrap();
+        let game = "Game 4: 3 red, 1 blue; 1 red, 4 yellow, 1 blue"
+            .parse::<Game>()
+            .unwrap();
+        let violations = constraints
+            .violations(&game)
+            .unwrap()
+            .into_iter()
+            .map(|v| (v.reveal, v.colour, v.revealed, v.allowed))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                (1, Some("red".to_owned()), 3, 1),
+                (2, Some("yellow".to_owned()), 4, 3),
+                (2, None, 6, 5),
+            ],
+            violations
+        );
+        assert!(!constraints.allows(&game).unwrap());
+    }
+
+    #[test]
+    fn it_builds_the_puzzle_rules_from_a_bag() {
+        let constraints = ConstraintSet::from_bag(&Cubes::puzzle_bag()).unwrap();
+        assert_eq!(
+            "blue<=14 && green<=13 && red<=12 && other<=0 && total<=39",
+            constraints.to_string()
+        );
+        assert_eq!(constraints, constraints.to_string().parse().unwrap());
+    }
+
+    #[test]
+    fn it_points_at_invalid_constraints() {
+        let e = "red<=12 && blue>3".parse::<ConstraintSet>().unwrap_err();
+        assert_eq!(&ParseErrorKind::ExpectedConstraint, e.kind());
+        assert_eq!(
+            Span {
+                line: 1,
+                column: 12
+            },
+            e.span()
+        );
+
+        let e = "red<=x".parse::<ConstraintSet>().unwrap_err();
+        assert!(matches!(e.kind(), ParseErrorKind::InvalidLimit(_)));
+        assert_eq!("x", e.text());
+
+        let e = "has 3".parse::<ConstraintSet>().unwrap_err();
+        assert_eq!(&ParseErrorKind::InvalidColour, e.kind());
+
+        for limit in ["x", "NaN", "inf", "-1", "-0.5"] {
+            let e = format!("red/blue<={limit}")
+                .parse::<ConstraintSet>()
+                .unwrap_err();
+            assert_eq!(&ParseErrorKind::InvalidRatio, e.kind());
+            assert_eq!(limit, e.text());
+        }
+        assert!("red/blue<=0".parse::<ConstraintSet>().is_ok());
+    }
+}
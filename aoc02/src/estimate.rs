@@ -0,0 +1,246 @@
+//! What a game's bag probably held, from its reveals.
+//!
+//! Each reveal is taken to be a handful of cubes drawn at random without
+//! replacement, with the cubes put back before the next one, so a reveal's
+//! probability is multivariate hypergeometric and reveals are independent.
+//! The minimum bag is [`Game::smallest_bag`].
+//!
+//! Bags keep getting more likely as they grow, as drawing without
+//! replacement tends to drawing with it, so [`maximum_likelihood`] needs the
+//! number of cubes in the bag and estimates how they split into colours.
+
+use std::cmp::Ordering;
+
+use crate::{Cubes, Game};
+
+/// Likelihoods this close are taken as equal, so ties go to the first bag
+/// tried rather than to rounding.
+const EPSILON: f64 = 1e-9;
+
+/// The most bags [`maximum_likelihood`] tries before giving up.
+pub const MAX_BAGS: u128 = 1_000_000;
+
+/// [`ln_choose`] sums at most this many terms, and takes larger ones from
+/// Stirling's series instead.
+const MAX_TERMS: u64 = 64;
+
+/// The most likely bag of a given size.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Estimate {
+    pub bag: Cubes,
+    /// Natural log of the probability of the game's reveals from `bag`.
+    pub log_likelihood: f64,
+}
+
+/// More ways to split a bag into colours than [`maximum_likelihood`] tries.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error(
+    "too many ways to split {total} cubes into {colours} colours, at most {MAX_BAGS} are tried"
+)]
+pub struct TooManyBags {
+    pub total: usize,
+    pub colours: usize,
+}
+
+/// The probability of `bag` producing every reveal of `game`, 0 if some
+/// reveal has more cubes of a colour than the bag.
+pub fn probability(game: &Game, bag: &Cubes) -> f64 {
+    log_likelihood(game, bag).exp()
+}
+
+/// Natural log of [`probability`], negative infinity for impossible bags.
+pub fn log_likelihood(game: &Game, bag: &Cubes) -> f64 {
+    let total = bag.iter().map(|(_, n)| n as u64).sum::<u64>();
+    game.reveals()
+        .iter()
+        .map(|reveal| {
+            if !reveal.fits_in(bag) {
+                return f64::NEG_INFINITY;
+            }
+            let drawn = reveal.iter().map(|(_, n)| n as u64).sum::<u64>();
+            reveal
+                .iter()
+                .map(|(colour, n)| ln_choose(bag.count(colour).into(), n.into()))
+                .sum::<f64>()
+                - ln_choose(total, drawn)
+        })
+        .sum()
+}
+
+/// The bag of exactly `total` cubes, of the colours the game reveals, under
+/// which its reveals are most likely.
+///
+/// Every way of splitting the cubes beyond the smallest bag between the
+/// colours is tried, so fails with [`TooManyBags`] if there are more than
+/// [`MAX_BAGS`]. Returns `None` if no bag of `total` cubes could have
+/// produced the reveals.
+pub fn maximum_likelihood(game: &Game, total: usize) -> Result<Option<Estimate>, TooManyBags> {
+    let minimum = game.smallest_bag();
+    let colours = minimum.colours().collect::<Vec<_>>();
+    let Some(spare) = minimum.sum().and_then(|n| total.checked_sub(n)) else {
+        return Ok(None);
+    };
+    if count_bags(colours.len(), spare).is_none_or(|bags| bags > MAX_BAGS) {
+        return Err(TooManyBags {
+            total,
+            colours: colours.len(),
+        });
+    }
+
+    let mut counts = colours
+        .iter()
+        .map(|c| minimum.count(c) as usize)
+        .collect::<Vec<_>>();
+    let mut best: Option<Estimate> = None;
+    search(&mut counts, 0, spare, &mut |counts| {
+        let Some(bag) = colours
+            .iter()
+            .zip(counts)
+            .map(|(&colour, &n)| Some((colour, u32::try_from(n).ok()?)))
+            .collect::<Option<Cubes>>()
+        else {
+            return;
+        };
+        let log_likelihood = log_likelihood(game, &bag);
+        let better = best
+            .as_ref()
+            .is_none_or(|best| compare(log_likelihood, best.log_likelihood) == Ordering::Greater);
+        if better {
+            best = Some(Estimate {
+                bag,
+                log_likelihood,
+            });
+        }
+    });
+    Ok(best)
+}
+
+/// How many ways there are to split `spare` cubes between `colours`, `None`
+/// if it overflows.
+fn count_bags(colours: usize, spare: usize) -> Option<u128> {
+    if colours == 0 {
+        return Some((spare == 0).into());
+    }
+    // C(spare + i, i) for each i up to colours - 1
+    (1..colours).try_fold(1u128, |bags, i| {
+        Some(bags.checked_mul((spare + i) as u128)? / i as u128)
+    })
+}
+
+/// Calls `visit` with every way of adding exactly `spare` cubes to the
+/// counts from `i` on.
+fn search(counts: &mut [usize], i: usize, spare: usize, visit: &mut impl FnMut(&[usize])) {
+    match counts.len() - i {
+        0 if spare == 0 => visit(counts),
+        0 => {}
+        1 => {
+            counts[i] += spare;
+            visit(counts);
+            counts[i] -= spare;
+        }
+        _ => {
+            let base = counts[i];
+            for extra in 0..=spare {
+                counts[i] = base + extra;
+                search(counts, i + 1, spare - extra, visit);
+            }
+            counts[i] = base;
+        }
+    }
+}
+
+fn compare(a: f64, b: f64) -> Ordering {
+    if (a - b).abs() <= EPSILON {
+        Ordering::Equal
+    } else {
+        a.total_cmp(&b)
+    }
+}
+
+/// Natural log of `n` choose `k`, negative infinity if `k > n`.
+fn ln_choose(n: u64, k: u64) -> f64 {
+    if k > n {
+        return f64::NEG_INFINITY;
+    }
+    let k = k.min(n - k);
+    if k < MAX_TERMS {
+        return (0..k).map(|i| ((n - i) as f64 / (i + 1) as f64).ln()).sum();
+    }
+    ln_factorial(n) - ln_factorial(k) - ln_factorial(n - k)
+}
+
+/// Natural log of `n!` from Stirling's series, good to about 1e-13 for `n`
+/// from [`MAX_TERMS`] on.
+fn ln_factorial(n: u64) -> f64 {
+    let n = n as f64;
+    n * n.ln() - n + 0.5 * (std::f64::consts::TAU * n).ln() + 1.0 / (12.0 * n)
+        - 1.0 / (360.0 * n.powi(3))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn game(s: &str) -> Game {
+        s.parse().unwrap()
+    }
+
+    fn bag(s: &str) -> Cubes {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn it_computes_hypergeometric_probabilities() {
+        let p = |g: &str, b: &str| probability(&game(g), &bag(b));
+        assert!((p("Game 1: 3 red", "3 red") - 1.0).abs() < 1e-12);
+        assert!((p("Game 1: 2 red; 2 blue", "2 red, 2 blue") - 1.0 / 36.0).abs() < 1e-12);
+        assert_eq!(0.0, p("Game 1: 3 red", "2 red, 5 blue"));
+
+        let draws = ["2 red", "1 red, 1 blue", "2 blue"];
+        let total = draws
+            .iter()
+            .map(|d| p(&format!("Game 1: {d}"), "2 red, 1 blue"))
+            .sum::<f64>();
+        assert!((total - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn it_handles_large_counts_quickly() {
+        assert!((ln_choose(100, 50) - 66.78384165201743).abs() < 1e-9);
+        assert!((ln_choose(70, 3) - 10.91034998259467).abs() < 1e-9);
+
+        let g = game("Game 1: 2000000000 red");
+        let ln = log_likelihood(&g, &bag("2000000000 red, 1 blue"));
+        assert!((ln + 2_000_000_001f64.ln()).abs() < 1e-6);
+        let g = game("Game 1: 1000000000 red");
+        assert_eq!(0.0, log_likelihood(&g, &bag("2000000000 red")));
+    }
+
+    #[test]
+    fn it_finds_the_most_likely_bag_of_a_size() {
+        let g = game("Game 1: 3 red; 1 red, 1 blue");
+        let estimate = maximum_likelihood(&g, 6).unwrap().unwrap();
+        assert_eq!(bag("5 red, 1 blue"), estimate.bag);
+        assert!(estimate.log_likelihood > log_likelihood(&g, &bag("4 red, 2 blue")));
+        assert!((estimate.log_likelihood - log_likelihood(&g, &estimate.bag)).abs() < 1e-9);
+
+        let g = game("Game 2: 3 red, 1 blue");
+        let estimate = |total| maximum_likelihood(&g, total).unwrap().map(|e| e.bag);
+        assert_eq!(Some(bag("8 red, 2 blue")), estimate(10));
+        assert_eq!(Some(g.smallest_bag()), estimate(4));
+        assert_eq!(None, estimate(3));
+    }
+
+    #[test]
+    fn it_refuses_to_try_too_many_bags() {
+        let g = game("Game 1: 1 red, 1 green, 1 blue, 1 yellow, 1 purple");
+        assert_eq!(
+            Err(TooManyBags {
+                total: 1000,
+                colours: 5
+            }),
+            maximum_likelihood(&g, 1000)
+        );
+        assert!(maximum_likelihood(&g, 30).is_ok());
+    }
+}
@@ -0,0 +1,460 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+    num::{IntErrorKind, ParseIntError},
+    str::FromStr,
+};
+
+use aoc_common::{parse, Error, Result, Solution};
+use constraints::ConstraintSet;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Serialize;
+
+pub mod constraints;
+pub mod estimate;
+pub mod report;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Game>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse::each_line(input)?)
+    }
+
+    fn part1(games: &Self::Input) -> Result<Self::Answer> {
+        sum_valid_games(games, &Cubes::puzzle_bag())
+    }
+
+    fn part2(games: &Self::Input) -> Result<Self::Answer> {
+        sum_power_of_smallest_valid_cubes(games)
+    }
+}
+
+/// Sums the ids of the games that could have been played with `bag`: no
+/// reveal has more cubes of a colour than the bag, or more cubes in total.
+/// Colours the bag doesn't mention count as none.
+pub fn sum_valid_games(games: &[Game], bag: &Cubes) -> Result<usize> {
+    sum_games_allowed_by(games, &ConstraintSet::from_bag(bag)?)
+}
+
+/// Sums the ids of the games following every one of `constraints`.
+pub fn sum_games_allowed_by(games: &[Game], constraints: &ConstraintSet) -> Result<usize> {
+    let mut sum = 0usize;
+    for game in games {
+        if constraints.allows(game)? {
+            sum = sum
+                .checked_add(game.id)
+                .ok_or(Error::Overflow("summing game ids"))?;
+        }
+    }
+    Ok(sum)
+}
+
+/// Sums the power of each game's smallest bag, over every colour seen in any
+/// game, so a game never revealing a colour others do has a power of 0.
+pub fn sum_power_of_smallest_valid_cubes(games: &[Game]) -> Result<usize> {
+    let colours = games
+        .iter()
+        .flat_map(|g| g.reveals.iter().flat_map(Cubes::colours))
+        .collect::<BTreeSet<_>>();
+    games.iter().try_fold(0usize, |sum, g| {
+        let bag = g.smallest_bag();
+        let power = colours
+            .iter()
+            .try_fold(1usize, |power, c| power.checked_mul(bag.count(c) as usize))
+            .ok_or_else(|| g.overflow(None, "multiplying cube counts"))?;
+        sum.checked_add(power)
+            .ok_or(Error::Overflow("summing powers"))
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct Game {
+    id: usize,
+    reveals: Vec<Cubes>,
+}
+
+impl Game {
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    pub fn reveals(&self) -> &[Cubes] {
+        &self.reveals
+    }
+
+    /// The fewest cubes of each colour the game could have been played with.
+    pub fn smallest_bag(&self) -> Cubes {
+        self.reveals
+            .iter()
+            .fold(Cubes::default(), |acc, r| acc.max(r))
+    }
+
+    /// An overflow in this game, with the 0-based index of the reveal if it
+    /// was in one.
+    pub(crate) fn overflow(&self, reveal: Option<usize>, action: &'static str) -> Error {
+        CubeOverflow {
+            game: self.id,
+            reveal: reveal.map(|i| i + 1),
+            action,
+        }
+        .into()
+    }
+}
+
+/// Cube arithmetic overflowing in a game.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub struct CubeOverflow {
+    pub game: usize,
+    /// 1-based index of the reveal, if the overflow was in one.
+    pub reveal: Option<usize>,
+    pub action: &'static str,
+}
+
+impl fmt::Display for CubeOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "arithmetic overflow while {} in game {}",
+            self.action, self.game
+        )?;
+        match self.reveal {
+            Some(reveal) => write!(f, ", reveal {reveal}"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl From<CubeOverflow> for Error {
+    fn from(e: CubeOverflow) -> Self {
+        Error::Solve(Box::new(e))
+    }
+}
+
+/// Cubes counted by colour. Colours without an entry have none.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(transparent)]
+pub struct Cubes {
+    counts: BTreeMap<String, u32>,
+}
+
+impl Cubes {
+    /// The bag from the puzzle: 12 red, 13 green and 14 blue cubes.
+    pub fn puzzle_bag() -> Self {
+        Cubes::from_iter([("red", 12), ("green", 13), ("blue", 14)])
+    }
+
+    pub fn count(&self, colour: &str) -> u32 {
+        self.counts.get(colour).copied().unwrap_or(0)
+    }
+
+    /// Colours with an entry, in alphabetical order.
+    pub fn colours(&self) -> impl Iterator<Item = &str> {
+        self.counts.keys().map(String::as_str)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.counts.iter().map(|(c, &n)| (c.as_str(), n))
+    }
+
+    /// Sets the count of `colour`, returning the previous one if any.
+    pub fn insert(&mut self, colour: impl Into<String>, count: u32) -> Option<u32> {
+        self.counts.insert(colour.into(), count)
+    }
+
+    /// Whether there are no more cubes of any colour than in `bag`.
+    pub fn fits_in(&self, bag: &Cubes) -> bool {
+        self.iter().all(|(colour, n)| n <= bag.count(colour))
+    }
+
+    /// The larger count of each colour in either.
+    pub fn max(&self, other: &Cubes) -> Cubes {
+        let mut max = self.clone();
+        for (colour, n) in other.iter() {
+            let count = max.counts.entry(colour.to_owned()).or_default();
+            *count = (*count).max(n);
+        }
+        max
+    }
+
+    /// The number of cubes of all colours, unless it overflows.
+    pub fn sum(&self) -> Option<usize> {
+        self.counts
+            .values()
+            .try_fold(0usize, |sum, &n| sum.checked_add(n as usize))
+    }
+
+    /// The counts of every colour with an entry multiplied together, unless
+    /// it overflows.
+    pub fn power(&self) -> Option<usize> {
+        self.counts
+            .values()
+            .try_fold(1usize, |power, &n| power.checked_mul(n as usize))
+    }
+}
+
+impl<C: Into<String>> FromIterator<(C, u32)> for Cubes {
+    fn from_iter<T: IntoIterator<Item = (C, u32)>>(iter: T) -> Self {
+        Cubes {
+            counts: iter.into_iter().map(|(c, n)| (c.into(), n)).collect(),
+        }
+    }
+}
+
+/// Counts and colours separated by commas, e.g. `3 blue, 4 red`.
+impl fmt::Display for Cubes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (colour, n)) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{n} {colour}")?;
+        }
+        Ok(())
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ParseErrorKind>;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseErrorKind {
+    #[error("expected exactly one ':'")]
+    Colon,
+    #[error("no game id found")]
+    MissingGameId,
+    #[error("invalid game id: {0}")]
+    InvalidGameId(ParseIntError),
+    #[error("invalid cube count: {0}")]
+    InvalidCount(ParseIntError),
+    #[error("cube count in reveal {reveal} of game {game} is out of range")]
+    CountOutOfRange { game: usize, reveal: usize },
+    #[error("expected a count followed by a colour, e.g. '3 blue'")]
+    InvalidCubes,
+    #[error("colour '{0}' appears more than once")]
+    DuplicateColour(String),
+}
+
+impl FromStr for Cubes {
+    type Err = ParseError;
+
+    /// Parses counts and colours separated by commas, e.g. `3 blue, 4 red`.
+    /// Colours are any run of letters. An empty string has no cubes.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_cubes(s).map_err(|(kind, token)| ParseError::new(kind, s, token))
+    }
+}
+
+/// Parses cubes like [`Cubes::from_str`], returning the token at fault on
+/// errors.
+fn parse_cubes(s: &str) -> Result<Cubes, (ParseErrorKind, &str)> {
+    let mut cubes = Cubes::default();
+    for item in s.split(',') {
+        let item = item.trim();
+        if item.is_empty() && !s.contains(',') {
+            continue;
+        }
+        let [n, colour] = item.split_whitespace().collect::<Vec<_>>()[..] else {
+            return Err((ParseErrorKind::InvalidCubes, item));
+        };
+        if !colour.chars().all(char::is_alphabetic) {
+            return Err((ParseErrorKind::InvalidCubes, item));
+        }
+        let count = n
+            .parse::<u32>()
+            .map_err(|e| (ParseErrorKind::InvalidCount(e), n))?;
+        if cubes.insert(colour, count).is_some() {
+            return Err((ParseErrorKind::DuplicateColour(colour.to_owned()), colour));
+        }
+    }
+    Ok(cubes)
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        static GAME_ID_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"Game (\d+)").unwrap());
+
+        let (game, reveals) = s
+            .split_once(':')
+            .ok_or_else(|| ParseError::new(ParseErrorKind::Colon, s, s))?;
+        if let Some(i) = reveals.find(':') {
+            return Err(ParseError::new(
+                ParseErrorKind::Colon,
+                s,
+                &reveals[i..i + 1],
+            ));
+        }
+
+        let id = GAME_ID_RE
+            .captures(game)
+            .ok_or_else(|| ParseError::new(ParseErrorKind::MissingGameId, s, game))?
+            .get(1)
+            .unwrap()
+            .as_str();
+        let id = id
+            .parse::<usize>()
+            .map_err(|e| ParseError::new(ParseErrorKind::InvalidGameId(e), s, id))?;
+
+        let reveals = reveals
+            .split(';')
+            .enumerate()
+            .map(|(i, rev)| {
+                parse_cubes(rev).map_err(|(kind, token)| {
+                    let kind = match kind {
+                        ParseErrorKind::InvalidCount(e)
+                            if *e.kind() == IntErrorKind::PosOverflow =>
+                        {
+                            ParseErrorKind::CountOutOfRange {
+                                game: id,
+                                reveal: i + 1,
+                            }
+                        }
+                        kind => kind,
+                    };
+                    ParseError::new(kind, s, token)
+                })
+            })
+            .collect::<Result<Vec<_>, Self::Err>>()?;
+
+        Ok(Game { id, reveals })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Span;
+
+    use super::*;
+
+    const TEST_GAMES: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+    #[test]
+    pub fn it_works_1() {
+        assert_eq!(
+            8,
+            sum_valid_games(&Day02::parse(TEST_GAMES).unwrap(), &Cubes::puzzle_bag()).unwrap()
+        );
+    }
+
+    #[test]
+    pub fn it_works_2() {
+        assert_eq!(
+            2286,
+            sum_power_of_smallest_valid_cubes(&Day02::parse(TEST_GAMES).unwrap()).unwrap()
+        );
+    }
+
+    #[test]
+    fn it_points_at_invalid_counts() {
+        let err = parse::each_line::<Game, _>(
+            "Game 1: 3 blue, 4 red
+Game 2: 1 blue; -3 green",
+        )
+        .unwrap_err();
+        assert!(matches!(err.kind(), ParseErrorKind::InvalidCount(_)));
+        assert_eq!(
+            Span {
+                line: 2,
+                column: 17
+            },
+            err.span()
+        );
+        assert_eq!("-3", err.text());
+    }
+
+    #[test]
+    fn it_names_the_game_and_reveal_of_out_of_range_counts() {
+        let games = parse::each_line::<Game, _>("Game 7: 1 blue; 300 green").unwrap();
+        assert_eq!(Some(300), games[0].smallest_bag().power());
+
+        let err = parse::each_line::<Game, _>(
+            "Game 1: 3 blue
+Game 7: 1 blue; 2 red, 4294967296 green",
+        )
+        .unwrap_err();
+        assert_eq!(
+            &ParseErrorKind::CountOutOfRange { game: 7, reveal: 2 },
+            err.kind()
+        );
+        assert_eq!("4294967296", err.text());
+        assert_eq!(
+            Span {
+                line: 2,
+                column: 24
+            },
+            err.span()
+        );
+    }
+
+    #[test]
+    fn it_names_the_game_of_overflowing_arithmetic() {
+        let max = u32::MAX;
+        let games = parse::each_line::<Game, _>(&format!(
+            "Game 1: 1 red
+Game 9: {max} red, {max} green, {max} blue"
+        ))
+        .unwrap();
+        let err = sum_power_of_smallest_valid_cubes(&games).unwrap_err();
+        assert_eq!(
+            "arithmetic overflow while multiplying cube counts in game 9",
+            err.to_string()
+        );
+        let Error::Solve(source) = err else {
+            panic!("expected a solve error, got {err:?}");
+        };
+        let overflow = source.downcast_ref::<CubeOverflow>().unwrap();
+        assert_eq!((9, None), (overflow.game, overflow.reveal));
+    }
+
+    #[test]
+    fn it_handles_any_colour() {
+        let games = parse::each_line::<Game, _>(
+            "Game 1: 3 blue, 4 red; 7 yellow
+Game 2: 1 blue, 2 red
+Game 3: 2 yellow, 1 red, 1 blue",
+        )
+        .unwrap();
+        assert_eq!(2, sum_valid_games(&games, &Cubes::puzzle_bag()).unwrap());
+        let bag = "12 red, 14 blue, 7 yellow".parse::<Cubes>().unwrap();
+        assert_eq!(6, sum_valid_games(&games, &bag).unwrap());
+        assert_eq!(
+            "3 blue, 4 red, 7 yellow",
+            games[0].smallest_bag().to_string()
+        );
+        assert_eq!(
+            3 * 4 * 7 + 2,
+            sum_power_of_smallest_valid_cubes(&games).unwrap()
+        );
+    }
+
+    #[test]
+    fn it_points_at_invalid_cubes() {
+        let err = "Game 1: 3 blue, 4; 1 red".parse::<Game>().unwrap_err();
+        assert_eq!(&ParseErrorKind::InvalidCubes, err.kind());
+        assert_eq!("4", err.text());
+
+        let err = "Game 1: 3 blue; 1 red, 2 red".parse::<Game>().unwrap_err();
+        assert_eq!(
+            &ParseErrorKind::DuplicateColour("red".to_owned()),
+            err.kind()
+        );
+        assert_eq!(
+            Span {
+                line: 1,
+                column: 26
+            },
+            err.span()
+        );
+    }
+}
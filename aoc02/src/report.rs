@@ -0,0 +1,231 @@
+//! Why each game could or couldn't have been played with a bag.
+
+use std::fmt;
+
+use aoc_common::{Error, Result};
+use serde::Serialize;
+
+use crate::{constraints::ConstraintSet, Cubes, Game};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Report {
+    pub bag: Cubes,
+    pub games: Vec<GameReport>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GameReport {
+    pub id: usize,
+    /// Whether no reveal broke the bag's limits.
+    pub feasible: bool,
+    pub violations: Vec<Violation>,
+    /// The fewest cubes of each colour the game could have been played with.
+    pub smallest_bag: Cubes,
+}
+
+/// A reveal with more cubes of a colour, or more cubes in total, than a
+/// constraint allows, like the bag's limits.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Violation {
+    /// 1-based index of the reveal in its game.
+    pub reveal: usize,
+    /// The colour over its limit, or `None` if the total is.
+    pub colour: Option<String>,
+    pub revealed: usize,
+    pub allowed: usize,
+    /// How many cubes too many were revealed.
+    pub excess: usize,
+}
+
+/// Checks every game against `bag` with the same
+/// [`ConstraintSet::from_bag`] rules as [`sum_valid_games`](crate::sum_valid_games).
+pub fn report(games: &[Game], bag: &Cubes) -> Result<Report> {
+    let constraints = ConstraintSet::from_bag(bag)?;
+    let games = games
+        .iter()
+        .map(|game| {
+            let violations = constraints.violations(game)?;
+            Ok(GameReport {
+                id: game.id(),
+                feasible: violations.is_empty(),
+                violations,
+                smallest_bag: game.smallest_bag(),
+            })
+        })
+        .collect::<Result<_>>()?;
+    Ok(Report {
+        bag: bag.clone(),
+        games,
+    })
+}
+
+impl Report {
+    /// Sum of the ids of the feasible games.
+    pub fn sum_feasible(&self) -> Result<usize> {
+        self.games
+            .iter()
+            .filter(|g| g.feasible)
+            .try_fold(0usize, |sum, g| sum.checked_add(g.id))
+            .ok_or(Error::Overflow("summing game ids"))
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let what = self.colour.as_deref().unwrap_or("in total");
+        write!(
+            f,
+            "reveal {}: {} {what} > {} by {}",
+            self.reveal, self.revealed, self.allowed, self.excess
+        )
+    }
+}
+
+/// A table with a row per game, and another per violation after the first.
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bags = self
+            .games
+            .iter()
+            .map(|g| g.smallest_bag.to_string())
+            .collect::<Vec<_>>();
+        let id_width = self
+            .games
+            .iter()
+            .map(|g| g.id.to_string().len())
+            .chain(["game".len()])
+            .max()
+            .unwrap();
+        let bag_width = bags
+            .iter()
+            .map(String::len)
+            .chain(["smallest bag".len()])
+            .max()
+            .unwrap();
+
+        writeln!(f, "bag: {}", self.bag)?;
+        write!(
+            f,
+            "{:>id_width$} | feasible | {:bag_width$} | violations",
+            "game", "smallest bag"
+        )?;
+        for (game, bag) in self.games.iter().zip(&bags) {
+            let feasible = if game.feasible { "yes" } else { "no" };
+            let mut violations = game.violations.iter();
+            write!(
+                f,
+                "\n{:>id_width$} | {feasible:8} | {bag:bag_width$} |",
+                game.id
+            )?;
+            if let Some(v) = violations.next() {
+                write!(f, " {v}")?;
+            }
+            for v in violations {
+                write!(f, "\n{:id_width$} | {:8} | {:bag_width$} | {v}", "", "", "")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{parse, Solution};
+
+    use super::*;
+    use crate::{sum_valid_games, Day02};
+
+    const TEST_GAMES: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+    #[test]
+    fn it_reports_violations() {
+        let games = Day02::parse(TEST_GAMES).unwrap();
+        let report = report(&games, &Cubes::puzzle_bag()).unwrap();
+        assert_eq!(
+            sum_valid_games(&games, &Cubes::puzzle_bag()).unwrap(),
+            report.sum_feasible().unwrap()
+        );
+        assert_eq!(
+            vec![Violation {
+                reveal: 1,
+                colour: Some("red".to_owned()),
+                revealed: 20,
+                allowed: 12,
+                excess: 8,
+            }],
+            report.games[2].violations
+        );
+        assert_eq!(
+            "6 blue, 13 green, 20 red",
+            report.games[2].smallest_bag.to_string()
+        );
+        assert_eq!(2, report.games[3].violations.len());
+    }
+
+    #[test]
+    fn it_reports_totals_over_the_bag() {
+        let games = parse::each_line::<Game, _>("Game 7: 5 red").unwrap();
+        let report = report(&games, &"1 red, 1 blue".parse().unwrap()).unwrap();
+        let excess = report.games[0]
+            .violations
+            .iter()
+            .map(|v| (v.colour.as_deref(), v.excess))
+            .collect::<Vec<_>>();
+        assert_eq!(vec![(Some("red"), 4), (None, 3)], excess);
+    }
+
+    #[test]
+    fn it_agrees_with_the_constraints_of_the_bag() {
+        let games = parse::each_line::<Game, _>(
+            "Game 1: 2 red, 2 blue; 1 green
+Game 2: 3 red; 1 yellow
+Game 3: 0 purple, 2 red
+Game 4: 2 blue, 2 red, 1 green
+Game 5: 4 blue",
+        )
+        .unwrap();
+        for bag in [
+            "2 red, 2 blue, 1 green",
+            "3 red, 1 yellow",
+            "5 blue",
+            "2 red",
+        ] {
+            let bag = bag.parse().unwrap();
+            let constraints = ConstraintSet::from_bag(&bag).unwrap();
+            let report = report(&games, &bag).unwrap();
+            for (game, row) in games.iter().zip(&report.games) {
+                assert_eq!(
+                    constraints.allows(game).unwrap(),
+                    row.feasible,
+                    "{bag}: {game:?}"
+                );
+            }
+            assert_eq!(
+                sum_valid_games(&games, &bag).unwrap(),
+                report.sum_feasible().unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn it_prints_a_table() {
+        let games = parse::each_line::<Game, _>(
+            "Game 1: 3 blue, 4 red
+Game 12: 2 red; 20 red, 15 blue",
+        )
+        .unwrap();
+        let report = report(&games, &Cubes::puzzle_bag()).unwrap();
+        assert_eq!(
+            "bag: 14 blue, 13 green, 12 red
+game | feasible | smallest bag    | violations
+   1 | yes      | 3 blue, 4 red   |
+  12 | no       | 15 blue, 20 red | reveal 2: 15 blue > 14 by 1
+     |          |                 | reveal 2: 20 red > 12 by 8",
+            report.to_string()
+        );
+    }
+}
@@ -0,0 +1,201 @@
+use aoc_common::{Error, Result, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const PARTS: &'static [aoc_common::Part] = &[aoc_common::Part::One];
+
+    type Input = String;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        validate_schematic(input)?;
+        Ok(input.to_owned())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer> {
+        sum_part_numbers_1(input)
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ParseErrorKind>;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseErrorKind {
+    #[error("empty schematic")]
+    Empty,
+    #[error("expected every line to be {expected} characters long")]
+    RaggedLine { expected: usize },
+    #[error("expected only ASCII characters")]
+    NonAscii,
+}
+
+/// Checks that the schematic is a non-empty ASCII grid, since the solver relies
+/// on every line having the same length.
+#[tracing::instrument(skip_all)]
+pub fn validate_schematic(s: &str) -> Result<(), ParseError> {
+    if let Some((i, c)) = s.char_indices().find(|(_, c)| !c.is_ascii()) {
+        return Err(ParseError::new(
+            ParseErrorKind::NonAscii,
+            s,
+            &s[i..i + c.len_utf8()],
+        ));
+    }
+
+    let mut lines = s.lines();
+    let expected = lines
+        .next()
+        .ok_or_else(|| ParseError::new(ParseErrorKind::Empty, s, s))?
+        .len();
+    match lines.find(|line| line.len() != expected) {
+        Some(line) => Err(ParseError::new(
+            ParseErrorKind::RaggedLine { expected },
+            s,
+            line,
+        )),
+        None => Ok(()),
+    }
+}
+
+#[tracing::instrument(skip_all)]
+pub fn sum_part_numbers_1(s: &str) -> Result<usize> {
+    let Some(line_len) = s.lines().next().map(str::len).filter(|&len| len > 0) else {
+        return Ok(0);
+    };
+    // Joined with `lines` rather than by removing '\n', so that CRLF line
+    // endings go too, as they do when validating
+    let s = s.lines().collect::<String>();
+    let has_adjacent_symbol = |start_ix, stop_ix| {
+        (start_ix % line_len > 0 && s.chars().nth(start_ix - 1).is_some_and(|c| c.is_symbol()))
+            || (stop_ix % line_len != line_len - 1
+                && s.chars().nth(stop_ix + 1).is_some_and(|c| c.is_symbol()))
+            || (start_ix >= line_len
+                && s.chars()
+                    .enumerate()
+                    .skip(if start_ix % line_len == 0 {
+                        start_ix - line_len
+                    } else {
+                        start_ix - line_len - 1
+                    })
+                    .take_while(|&(i, _)| {
+                        i <= if stop_ix % line_len == line_len - 1 {
+                            stop_ix - line_len
+                        } else {
+                            stop_ix - line_len + 1
+                        }
+                    })
+                    .any(|(_, c)| c.is_symbol()))
+            || (start_ix < s.len() - line_len
+                && s.chars()
+                    .enumerate()
+                    .skip(if start_ix % line_len == 0 {
+                        start_ix + line_len
+                    } else {
+                        start_ix + line_len - 1
+                    })
+                    .take_while(|&(i, _)| {
+                        i <= if stop_ix % line_len == line_len - 1 {
+                            stop_ix + line_len
+                        } else {
+                            stop_ix + line_len + 1
+                        }
+                    })
+                    .any(|(_, c)| c.is_symbol()))
+    };
+
+    let mut sum = 0;
+    let mut current_part = None;
+    for (i, c) in s.chars().enumerate() {
+        if i % line_len == 0 {
+            current_part = None;
+        }
+
+        if current_part.is_none() && c.is_ascii_digit() {
+            current_part = Some(Part {
+                number: c.to_digit(10).unwrap() as usize,
+                start_ix: i,
+                stop_ix: i,
+            });
+        } else if current_part.is_some() && c.is_ascii_digit() {
+            current_part = Some(Part {
+                number: current_part
+                    .unwrap()
+                    .number
+                    .checked_mul(10)
+                    .and_then(|n| n.checked_add(c.to_digit(10).unwrap() as usize))
+                    .ok_or(Error::Overflow("reading a part number"))?,
+                start_ix: current_part.unwrap().start_ix,
+                stop_ix: i,
+            })
+        } else {
+            current_part = None;
+        }
+
+        if let Some(current_part) = current_part.filter(|_| {
+            i % line_len == line_len - 1
+                || s.chars().nth(i + 1).is_some_and(|c| !c.is_ascii_digit())
+        }) {
+            if has_adjacent_symbol(current_part.start_ix, current_part.stop_ix) {
+                sum = current_part
+                    .number
+                    .checked_add(sum)
+                    .ok_or(Error::Overflow("summing part numbers"))?;
+            }
+        }
+    }
+    Ok(sum)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Part {
+    number: usize,
+    start_ix: usize,
+    stop_ix: usize,
+}
+
+trait CharExt {
+    fn is_symbol(&self) -> bool;
+}
+
+impl CharExt for char {
+    fn is_symbol(&self) -> bool {
+        !self.is_ascii_digit() && !self.is_ascii_whitespace() && *self != '.'
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const TEST_ENGINE_SCHEMATIC: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+    #[test]
+    fn it_works_1() {
+        assert_eq!(4361, sum_part_numbers_1(TEST_ENGINE_SCHEMATIC).unwrap());
+    }
+
+    #[test]
+    fn it_rejects_ragged_schematics() {
+        let err = validate_schematic("467..114..\n...*.....\n..35..633.").unwrap_err();
+        assert_eq!(&ParseErrorKind::RaggedLine { expected: 10 }, err.kind());
+        assert_eq!(2, err.span().line);
+    }
+
+    #[test]
+    fn it_works_with_crlf_line_endings_1() {
+        let schematic = "467..114..\r\n...*......\r\n..35..633.\r\n";
+        validate_schematic(schematic).unwrap();
+        assert_eq!(502, sum_part_numbers_1(schematic).unwrap());
+        let schematic = TEST_ENGINE_SCHEMATIC.replace('\n', "\r\n");
+        assert_eq!(4361, sum_part_numbers_1(&schematic).unwrap());
+    }
+}
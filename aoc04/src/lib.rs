@@ -0,0 +1,102 @@
+use std::{collections::HashSet, num::ParseIntError, str::FromStr};
+
+use aoc_common::{parse, Error, Part, Result, Solution};
+use itertools::Itertools;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const PARTS: &'static [Part] = &[Part::One];
+
+    type Input = Vec<Card>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse::each_line(input)?)
+    }
+
+    fn part1(cards: &Self::Input) -> Result<Self::Answer> {
+        count_points(cards)
+    }
+}
+
+pub fn count_points(cards: &[Card]) -> Result<u32> {
+    cards.iter().try_fold(0u32, |sum, card| {
+        let win_count = card.mine.intersection(&card.winning).count();
+        let points = match win_count {
+            0 => Some(0),
+            _ => 2u32.checked_pow(win_count as u32 - 1),
+        };
+        points
+            .and_then(|points| sum.checked_add(points))
+            .ok_or(Error::Overflow("counting points"))
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Card {
+    mine: HashSet<u8>,
+    winning: HashSet<u8>,
+}
+
+pub type ParseError = aoc_common::ParseError<ParseErrorKind>;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseErrorKind {
+    #[error("expected ':' after the card number")]
+    MissingColon,
+    #[error("expected exactly one '|'")]
+    Bar,
+    #[error("invalid number: {0}")]
+    InvalidNumber(ParseIntError),
+}
+
+impl FromStr for Card {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let numbers = parse::after_colon(s)
+            .ok_or_else(|| ParseError::new(ParseErrorKind::MissingColon, s, &s[s.len()..]))?;
+        let (mine, winning) = numbers
+            .split('|')
+            .map(|nums| {
+                parse::numbers::<u8>(nums)
+                    .map(|nums| nums.into_iter().collect::<HashSet<_>>())
+                    .map_err(|(n, e)| ParseError::new(ParseErrorKind::InvalidNumber(e), s, n))
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .collect_tuple()
+            .ok_or_else(|| ParseError::new(ParseErrorKind::Bar, s, numbers))?;
+        Ok(Card { mine, winning })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_CARDS: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+    #[test]
+    fn it_works_1() {
+        assert_eq!(
+            13,
+            count_points(&Day04::parse(TEST_CARDS).unwrap()).unwrap()
+        );
+    }
+
+    #[test]
+    fn it_points_at_invalid_numbers() {
+        let err = "Card 1: 41 48 | 83 -86".parse::<Card>().unwrap_err();
+        assert!(matches!(err.kind(), ParseErrorKind::InvalidNumber(_)));
+        assert_eq!(20, err.span().column);
+        assert_eq!("-86", err.text());
+    }
+}
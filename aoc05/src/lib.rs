@@ -0,0 +1,233 @@
+use std::{num::ParseIntError, ops::Range, str::FromStr};
+
+use aoc_common::{parse, Error, Part, Result, Solution};
+use regex::Regex;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const PARTS: &'static [Part] = &[Part::One];
+
+    type Input = Almanac;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(almanac: &Self::Input) -> Result<Self::Answer> {
+        find_lowest_location_1(almanac)
+    }
+}
+
+#[tracing::instrument(skip_all)]
+pub fn find_lowest_location_1(almanac: &Almanac) -> Result<u32> {
+    almanac
+        .seeds
+        .iter()
+        .map(|&seed| {
+            almanac
+                .maps
+                .iter()
+                .try_fold(seed, |acc, el| el.map_num(acc))
+        })
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .min()
+        .ok_or_else(|| NoSeeds.into())
+}
+
+/// An almanac without seeds has no lowest location.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("no seeds")]
+pub struct NoSeeds;
+
+impl From<NoSeeds> for Error {
+    fn from(e: NoSeeds) -> Self {
+        Error::Solve(Box::new(e))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Almanac {
+    seeds: Vec<u32>,
+    maps: Vec<CategoryMap>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CategoryMap {
+    from: String,
+    to: String,
+    maps: Vec<Map>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Map {
+    src_range: Range<u64>,
+    offset: i64,
+}
+
+impl CategoryMap {
+    fn map_num(&self, num: u32) -> Result<u32> {
+        if let Some(matching_map) = self
+            .maps
+            .iter()
+            .find(|&map| map.src_range.contains(&(num as u64)))
+        {
+            u32::try_from(num as i64 + matching_map.offset)
+                .map_err(|_| Error::Overflow("mapping a number to the next category"))
+        } else {
+            Ok(num)
+        }
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ParseErrorKind>;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseErrorKind {
+    #[error("expected `seeds:` followed by numbers")]
+    MissingSeeds,
+    #[error("missing map header")]
+    MissingHeader,
+    #[error("expected a header like `seed-to-soil map:`")]
+    InvalidHeader,
+    #[error("expected three numbers")]
+    InvalidMapLine,
+    #[error("invalid number: {0}")]
+    InvalidNumber(ParseIntError),
+}
+
+impl FromStr for Almanac {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let seeds_line = s.lines().next().unwrap_or(s);
+        let seeds = parse::after_colon(seeds_line)
+            .ok_or_else(|| ParseError::new(ParseErrorKind::MissingSeeds, s, seeds_line))?;
+        let seeds = parse::numbers::<u32>(seeds)
+            .map_err(|(n, e)| ParseError::new(ParseErrorKind::InvalidNumber(e), s, n))?;
+
+        let maps = parse::blocks(s)
+            .skip(1)
+            .map(|block| block.parse::<CategoryMap>().map_err(|e| e.within(s, block)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Almanac { seeds, maps })
+    }
+}
+
+impl FromStr for CategoryMap {
+    type Err = ParseError;
+
+    #[tracing::instrument(name = "category_map", skip_all)]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let categories_re = Regex::new(r"(.+)-to-(.+) map").unwrap();
+        let map_re = Regex::new(r"(\d+) (\d+) (\d+)").unwrap();
+        let mut lines = s.lines();
+
+        let header = lines
+            .next()
+            .ok_or_else(|| ParseError::new(ParseErrorKind::MissingHeader, s, s))?;
+        let categories_caps = categories_re
+            .captures(header)
+            .ok_or_else(|| ParseError::new(ParseErrorKind::InvalidHeader, s, header))?;
+        let from = categories_caps[1].to_owned();
+        let to = categories_caps[2].to_owned();
+
+        let maps = lines
+            .map(|line| {
+                let map_caps = map_re
+                    .captures(line)
+                    .ok_or_else(|| ParseError::new(ParseErrorKind::InvalidMapLine, s, line))?;
+                let num = |i| {
+                    let n = map_caps.get(i).unwrap().as_str();
+                    n.parse::<u32>()
+                        .map_err(|e| ParseError::new(ParseErrorKind::InvalidNumber(e), s, n))
+                };
+                let dest_range_start = num(1)?;
+                let source_range_start = num(2)?;
+                let range_len = num(3)?;
+                Ok(Map {
+                    src_range: source_range_start as u64
+                        ..source_range_start as u64 + range_len as u64 + 1,
+                    offset: dest_range_start as i64 - source_range_start as i64,
+                })
+            })
+            .collect::<Result<Vec<_>, Self::Err>>()?;
+
+        Ok(CategoryMap { from, to, maps })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Span;
+
+    use super::*;
+
+    const TEST_ALMANAC: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+    #[test]
+    fn it_works_1() {
+        let almanac = Day05::parse(TEST_ALMANAC).unwrap();
+        assert_eq!(35, find_lowest_location_1(&almanac).unwrap());
+    }
+
+    #[test]
+    fn it_points_at_bad_map_lines() {
+        let almanac = "seeds: 79 14
+
+seed-to-soil map:
+50 98 2
+52 50
+
+soil-to-fertilizer map:
+0 15 37";
+        let err = almanac.parse::<Almanac>().unwrap_err();
+        assert_eq!(&ParseErrorKind::InvalidMapLine, err.kind());
+        assert_eq!(Span { line: 5, column: 1 }, err.span());
+        assert_eq!("52 50", err.text());
+    }
+
+    #[test]
+    fn it_fails_to_solve_without_seeds_1() {
+        let almanac = "seeds:\n\nseed-to-soil map:\n50 98 2".parse().unwrap();
+        let err = find_lowest_location_1(&almanac).unwrap_err();
+        assert_eq!("no seeds", err.to_string());
+        assert!(matches!(err, Error::Solve(_)));
+    }
+}
@@ -0,0 +1,199 @@
+use std::{num::ParseIntError, str::FromStr};
+
+use aoc_common::{parse, Error, Result, Solution};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Races;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(races: &Self::Input) -> Result<Self::Answer> {
+        product_of_ways_to_win_1(races)
+    }
+
+    fn part2(races: &Self::Input) -> Result<Self::Answer> {
+        count_ways_to_win_2(races)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Races {
+    times: Vec<usize>,
+    distances: Vec<usize>,
+    /// Each line's digits as written, zeros padding a number included
+    time_digits: String,
+    distance_digits: String,
+}
+
+pub type ParseError = aoc_common::ParseError<ParseErrorKind>;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseErrorKind {
+    #[error("missing times")]
+    MissingTimes,
+    #[error("missing distances")]
+    MissingDistances,
+    #[error("nothing after ':'")]
+    MissingColon,
+    #[error("invalid number: {0}")]
+    InvalidNumber(ParseIntError),
+}
+
+impl FromStr for Races {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut iter = s.lines().map(|line| {
+            let nums = parse::after_colon(line)
+                .ok_or_else(|| ParseError::new(ParseErrorKind::MissingColon, s, line))?;
+            let numbers = parse::numbers::<usize>(nums)
+                .map_err(|(n, e)| ParseError::new(ParseErrorKind::InvalidNumber(e), s, n))?;
+            let digits = nums
+                .split_whitespace()
+                .map(|n| n.strip_prefix('+').unwrap_or(n))
+                .collect::<String>();
+            Ok((numbers, digits))
+        });
+        let end = &s[s.len()..];
+
+        let (times, time_digits) = iter
+            .next()
+            .ok_or_else(|| ParseError::new(ParseErrorKind::MissingTimes, s, end))??;
+        let (distances, distance_digits) = iter
+            .next()
+            .ok_or_else(|| ParseError::new(ParseErrorKind::MissingDistances, s, end))??;
+
+        Ok(Races {
+            times,
+            distances,
+            time_digits,
+            distance_digits,
+        })
+    }
+}
+
+pub fn product_of_ways_to_win_1(races: &Races) -> Result<usize> {
+    races
+        .times
+        .iter()
+        .zip(&races.distances)
+        .try_fold(1usize, |product, (&time, &dist)| {
+            product.checked_mul(count_ways_to_win(time, dist))
+        })
+        .ok_or(Error::Overflow("multiplying ways to win"))
+}
+
+/// Part 2 reads each line as one number, ignoring the spaces between them.
+pub fn count_ways_to_win_2(races: &Races) -> Result<usize> {
+    let time = kern(&races.time_digits)?;
+    let record_dist = kern(&races.distance_digits)?;
+    Ok(count_ways_to_win(time, record_dist))
+}
+
+fn kern(digits: &str) -> Result<usize> {
+    if digits.is_empty() {
+        return Ok(0);
+    }
+    digits
+        .parse()
+        .map_err(|_| Error::Overflow("joining the numbers on a line"))
+}
+
+/// The traveled distance grows until half the race is spent holding the button
+/// and shrinks symmetrically after, so only the shortest winning hold is
+/// searched for.
+fn count_ways_to_win(race_ms: usize, record_dist: usize) -> usize {
+    let traveled_dist = |hold_ms: usize| hold_ms as u128 * (race_ms - hold_ms) as u128;
+    let half = race_ms / 2;
+
+    let (mut lo, mut hi) = (1, half + 1);
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if traveled_dist(mid) > record_dist as u128 {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+
+    if lo > half {
+        0
+    } else {
+        race_ms - 2 * lo + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Span;
+
+    use super::*;
+
+    const TEST_RACES: &str = "Time:      7  15   30
+Distance:  9  40  200";
+
+    #[test]
+    fn it_works_1() {
+        assert_eq!(
+            288,
+            product_of_ways_to_win_1(&Day06::parse(TEST_RACES).unwrap()).unwrap()
+        );
+    }
+
+    #[test]
+    fn it_works_2() {
+        assert_eq!(
+            71503,
+            count_ways_to_win_2(&Day06::parse(TEST_RACES).unwrap()).unwrap()
+        );
+    }
+
+    #[test]
+    fn it_keeps_zeros_padding_numbers_2() {
+        let races = "Time:      7  015\nDistance:  9  040".parse().unwrap();
+        assert_eq!(
+            count_ways_to_win(7015, 9040),
+            count_ways_to_win_2(&races).unwrap()
+        );
+        assert_eq!(
+            count_ways_to_win(7, 9) * count_ways_to_win(15, 40),
+            product_of_ways_to_win_1(&races).unwrap()
+        );
+    }
+
+    #[test]
+    fn it_points_at_invalid_numbers() {
+        let err = "Time:      7  15   30\nDistance:  9  4O  200"
+            .parse::<Races>()
+            .unwrap_err();
+        assert!(matches!(err.kind(), ParseErrorKind::InvalidNumber(_)));
+        assert_eq!(
+            Span {
+                line: 2,
+                column: 15
+            },
+            err.span()
+        );
+        assert_eq!("4O", err.text());
+    }
+
+    #[test]
+    fn it_counts_ways_to_win_without_trying_every_hold_time() {
+        for race_ms in 0..50 {
+            for record_dist in 0..400 {
+                let brute_force = (1..race_ms)
+                    .filter(|&hold_ms| hold_ms * (race_ms - hold_ms) > record_dist)
+                    .count();
+                assert_eq!(brute_force, count_ways_to_win(race_ms, record_dist));
+            }
+        }
+        assert_eq!(usize::MAX - 3, count_ways_to_win(usize::MAX, usize::MAX));
+    }
+}
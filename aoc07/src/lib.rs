@@ -1,31 +1,77 @@
-use std::{cmp::Ordering, collections::HashMap, error::Error, fs, str::FromStr};
+use std::{cmp::Ordering, collections::HashMap, num::ParseIntError, str::FromStr};
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let input = fs::read_to_string("input.txt")?;
-    println!("{}", find_total_winnings(&input)?);
-    Ok(())
+use aoc_common::{Error, Part, Result, Solution};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const PARTS: &'static [Part] = &[Part::One];
+
+    type Input = Vec<(Hand, usize)>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input
+            .lines()
+            .map(|l| parse_hand_and_bid(l).map_err(|e| e.within(input, l)))
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part1(hands: &Self::Input) -> Result<Self::Answer> {
+        find_total_winnings(hands)
+    }
 }
 
-fn find_total_winnings(s: &str) -> Result<usize, Box<dyn Error>> {
-    let mut parsed = s
-        .lines()
-        .map(|l| {
-            let mut iter = l.split_whitespace();
-            let hand = iter.next().ok_or("missing hand")?.parse::<Hand>()?;
-            let bid = iter.next().ok_or("missing bid")?.parse::<usize>()?;
-            Ok::<_, Box<dyn Error>>((hand, bid))
-        })
-        .collect::<Result<Vec<_>, _>>()?;
-    parsed.sort_by_key(|(hand, _bid)| hand.clone());
-    Ok(parsed
+pub type ParseError = aoc_common::ParseError<ParseErrorKind>;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseErrorKind {
+    #[error("missing hand")]
+    MissingHand,
+    #[error("missing bid")]
+    MissingBid,
+    #[error("invalid bid: {0}")]
+    InvalidBid(ParseIntError),
+    #[error("invalid card")]
+    InvalidCard,
+    #[error("expected 5 cards, got {0}")]
+    HandLength(usize),
+}
+
+fn parse_hand_and_bid(line: &str) -> Result<(Hand, usize), ParseError> {
+    let end = &line[line.len()..];
+    let mut iter = line.split_whitespace();
+    let hand = iter
+        .next()
+        .ok_or_else(|| ParseError::new(ParseErrorKind::MissingHand, line, end))?;
+    let bid = iter
+        .next()
+        .ok_or_else(|| ParseError::new(ParseErrorKind::MissingBid, line, end))?;
+    Ok((
+        hand.parse::<Hand>().map_err(|e| e.within(line, hand))?,
+        bid.parse::<usize>()
+            .map_err(|e| ParseError::new(ParseErrorKind::InvalidBid(e), line, bid))?,
+    ))
+}
+
+#[tracing::instrument(skip_all)]
+pub fn find_total_winnings(hands: &[(Hand, usize)]) -> Result<usize> {
+    let mut ranked = hands.iter().collect::<Vec<_>>();
+    ranked.sort_by_key(|(hand, _bid)| hand);
+    ranked
         .into_iter()
         .enumerate()
-        .map(|(rank_from_zero, (_hand, bid))| (rank_from_zero + 1) * bid)
-        .sum())
+        .try_fold(0usize, |sum, (rank_from_zero, (_hand, bid))| {
+            (rank_from_zero + 1)
+                .checked_mul(*bid)
+                .and_then(|winnings| sum.checked_add(winnings))
+        })
+        .ok_or(Error::Overflow("summing winnings"))
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
-struct Hand {
+pub struct Hand {
     cards: [Card; 5],
 }
 
@@ -105,23 +151,29 @@ impl PartialOrd for Hand {
 }
 
 impl FromStr for Hand {
-    type Err = Box<dyn Error>;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let cards = s
-            .chars()
-            .map(|c| match c {
+            .char_indices()
+            .map(|(i, c)| match c {
                 'A' => Ok(Card::A),
                 'K' => Ok(Card::K),
                 'Q' => Ok(Card::Q),
                 'J' => Ok(Card::J),
                 'T' => Ok(Card::T),
-                _ if c.is_ascii_digit() => Ok(Card::Digit(c.to_digit(10).unwrap() as u8)),
-                _ => Err(format!("Invalid card: {c}")),
+                _ if c.is_ascii_digit() => Ok(Card::Digit(c as u8 - b'0')),
+                _ => Err(ParseError::new(
+                    ParseErrorKind::InvalidCard,
+                    s,
+                    &s[i..i + c.len_utf8()],
+                )),
             })
-            .collect::<Result<Vec<_>, _>>()?
+            .collect::<Result<Vec<_>, _>>()?;
+        let len = cards.len();
+        let cards = cards
             .try_into()
-            .unwrap();
+            .map_err(|_| ParseError::new(ParseErrorKind::HandLength(len), s, s))?;
         Ok(Hand { cards })
     }
 }
@@ -183,6 +235,19 @@ T55J5 684
 KK677 28
 KTJJT 220
 QQQJA 483";
-        assert_eq!(6440, find_total_winnings(s).unwrap());
+        assert_eq!(
+            6440,
+            find_total_winnings(&Day07::parse(s).unwrap()).unwrap()
+        );
+    }
+
+    #[test]
+    fn it_points_at_invalid_hands() {
+        let err = Day07::parse("32T3K 765\nT55X5 684").unwrap_err();
+        assert!(err.to_string().ends_with("2 | T55X5 684\n  |    ^"));
+
+        let err = parse_hand_and_bid("KK6772 28").unwrap_err();
+        assert_eq!(&ParseErrorKind::HandLength(6), err.kind());
+        assert_eq!("KK6772", err.text());
     }
 }
@@ -0,0 +1,188 @@
+use std::{collections::HashMap, str::FromStr};
+
+use aoc_common::{parse, Error, Part, Result, Solution};
+use itertools::Itertools;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const PARTS: &'static [Part] = &[Part::One];
+
+    type Input = Network;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(network: &Self::Input) -> Result<Self::Answer> {
+        steps_until_zzz(network)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Network {
+    lr: Vec<Direction>,
+    nodes: HashMap<String, (String, String)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    L,
+    R,
+}
+
+pub type ParseError = aoc_common::ParseError<ParseErrorKind>;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseErrorKind {
+    #[error("expected directions, a blank line and then nodes")]
+    MissingNodes,
+    #[error("expected at least one direction")]
+    MissingDirections,
+    #[error("expected 'L' or 'R'")]
+    InvalidDirection,
+    #[error("expected a node like `AAA = (BBB, CCC)`")]
+    InvalidNode,
+}
+
+impl FromStr for Network {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        static MAP_ITEM_RE: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(r"(?<key>\w\w\w) = \((?<left>\w\w\w), (?<right>\w\w\w)\)").unwrap()
+        });
+
+        let (lr, nodes) = parse::blocks(s)
+            .next_tuple()
+            .ok_or_else(|| ParseError::new(ParseErrorKind::MissingNodes, s, &s[s.len()..]))?;
+        if lr.is_empty() {
+            return Err(ParseError::new(ParseErrorKind::MissingDirections, s, lr));
+        }
+        let lr = lr
+            .char_indices()
+            .map(|(i, c)| match c {
+                'L' => Ok(Direction::L),
+                'R' => Ok(Direction::R),
+                _ => Err(ParseError::new(
+                    ParseErrorKind::InvalidDirection,
+                    s,
+                    &lr[i..i + c.len_utf8()],
+                )),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let nodes = nodes
+            .lines()
+            .map(|line| {
+                if let Some(caps) = MAP_ITEM_RE.captures(line) {
+                    let key = caps["key"].to_owned();
+                    let (left, right) = (caps["left"].to_owned(), caps["right"].to_owned());
+                    Ok((key, (left, right)))
+                } else {
+                    Err(ParseError::new(ParseErrorKind::InvalidNode, s, line))
+                }
+            })
+            .collect::<Result<HashMap<_, _>, _>>()?;
+
+        Ok(Network { lr, nodes })
+    }
+}
+
+#[tracing::instrument(skip_all)]
+pub fn steps_until_zzz(network: &Network) -> Result<usize> {
+    let Network { lr, nodes } = network;
+    let mut current_node = "AAA";
+    let mut steps = 0;
+
+    while current_node != "ZZZ" {
+        // Past this many steps some (node, direction) pair has repeated, so
+        // we're walking in a loop that doesn't pass through ZZZ
+        if steps > nodes.len() * lr.len() {
+            return Err(WalkError::Unreachable.into());
+        }
+
+        let dir = lr[steps % lr.len()];
+        current_node = nodes
+            .get(current_node)
+            .map(|(left, right)| match dir {
+                Direction::L => left,
+                Direction::R => right,
+            })
+            .ok_or_else(|| WalkError::MissingNode(current_node.to_owned()))?;
+        steps += 1;
+    }
+
+    Ok(steps)
+}
+
+/// Why [`steps_until_zzz`] couldn't walk from AAA to ZZZ.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum WalkError {
+    #[error("ZZZ is unreachable from AAA")]
+    Unreachable,
+    #[error("node {0} is missing from the network")]
+    MissingNode(String),
+}
+
+impl From<WalkError> for Error {
+    fn from(e: WalkError) -> Self {
+        Error::Solve(Box::new(e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Span;
+
+    use super::*;
+
+    const TEST_MAP_1: &str = "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)";
+
+    const TEST_MAP_2: &str = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+
+    #[test]
+    fn it_works_1() {
+        assert_eq!(
+            2,
+            steps_until_zzz(&Day08::parse(TEST_MAP_1).unwrap()).unwrap()
+        );
+        assert_eq!(
+            6,
+            steps_until_zzz(&Day08::parse(TEST_MAP_2).unwrap()).unwrap()
+        );
+    }
+
+    #[test]
+    fn it_points_at_invalid_directions() {
+        let err = "LXR\n\nAAA = (ZZZ, ZZZ)".parse::<Network>().unwrap_err();
+        assert_eq!(&ParseErrorKind::InvalidDirection, err.kind());
+        assert_eq!(Span { line: 1, column: 2 }, err.span());
+    }
+
+    #[test]
+    fn it_detects_unreachable_zzz() {
+        let network = "L\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)"
+            .parse()
+            .unwrap();
+        let err = steps_until_zzz(&network).unwrap_err();
+        assert_eq!("ZZZ is unreachable from AAA", err.to_string());
+        assert!(matches!(err, Error::Solve(_)));
+    }
+}
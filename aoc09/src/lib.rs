@@ -0,0 +1,143 @@
+use std::num::ParseIntError;
+
+use aoc_common::{parse, Error, Result, Solution};
+use itertools::Itertools;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Vec<isize>>;
+    type Answer = isize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(histories: &Self::Input) -> Result<Self::Answer> {
+        sum_predictions(histories, predict_next)
+    }
+
+    fn part2(histories: &Self::Input) -> Result<Self::Answer> {
+        sum_predictions(histories, predict_first)
+    }
+}
+
+fn sum_predictions(
+    histories: &[Vec<isize>],
+    predict: fn(&[isize]) -> Result<isize>,
+) -> Result<isize> {
+    histories.iter().try_fold(0isize, |sum, h| {
+        sum.checked_add(predict(h)?)
+            .ok_or(Error::Overflow("summing predictions"))
+    })
+}
+
+pub type ParseError = aoc_common::ParseError<ParseErrorKind>;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseErrorKind {
+    #[error("invalid number: {0}")]
+    InvalidNumber(ParseIntError),
+}
+
+pub fn parse(s: &str) -> Result<Vec<Vec<isize>>, ParseError> {
+    s.lines()
+        .map(|l| {
+            parse::numbers(l)
+                .map_err(|(n, e)| ParseError::new(ParseErrorKind::InvalidNumber(e), s, n))
+        })
+        .collect()
+}
+
+/// Rows of differences down to all zeros. Running out of values first, e.g. for
+/// a history of one value, leaves an empty bottom row which counts as zeros.
+#[tracing::instrument(skip_all)]
+fn extrapolate(history: &[isize]) -> Result<Vec<Vec<isize>>> {
+    let mut extrapolation = Vec::<Vec<isize>>::new();
+    let mut extrapolation_done = false;
+    extrapolation.push(history.to_vec());
+    while !extrapolation_done {
+        let steps = extrapolation
+            .last()
+            .unwrap()
+            .iter()
+            .tuple_windows()
+            .map(|(&x1, &x2)| {
+                x2.checked_sub(x1)
+                    .ok_or(Error::Overflow("extrapolating a history"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        if steps.iter().all(|&dx| dx == 0) {
+            extrapolation_done = true;
+        }
+
+        extrapolation.push(steps);
+    }
+    Ok(extrapolation)
+}
+
+pub fn predict_next(history: &[isize]) -> Result<isize> {
+    let extrapolation = extrapolate(history)?;
+    extrapolation
+        .into_iter()
+        .rev()
+        .map(|seq| seq.last().copied().unwrap_or(0))
+        .try_fold(0isize, |val_below, val_left| {
+            val_left.checked_add(val_below)
+        })
+        .ok_or(Error::Overflow("predicting the next value"))
+}
+
+pub fn predict_first(history: &[isize]) -> Result<isize> {
+    let extrapolation = extrapolate(history)?;
+
+    extrapolation
+        .into_iter()
+        .rev()
+        .map(|seq| seq.first().copied().unwrap_or(0))
+        .try_fold(0isize, |val_below, val_right| {
+            val_right.checked_sub(val_below)
+        })
+        .ok_or(Error::Overflow("predicting the first value"))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Span;
+
+    use super::*;
+
+    const TEST_REPORT: &str = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+
+    #[test]
+    fn it_works_1() {
+        let histories = parse(TEST_REPORT).unwrap();
+        assert_eq!(114, Day09::part1(&histories).unwrap())
+    }
+
+    #[test]
+    fn it_works_2() {
+        let histories = parse(TEST_REPORT).unwrap();
+        assert_eq!(2, Day09::part2(&histories).unwrap())
+    }
+
+    #[test]
+    fn it_points_at_invalid_numbers() {
+        let err = parse("0 3 6 9 12 15\n1 3 6 1O 15 21").unwrap_err();
+        assert_eq!(Span { line: 2, column: 7 }, err.span());
+        assert_eq!("1O", err.text());
+    }
+
+    #[test]
+    fn it_handles_short_and_extreme_histories() {
+        assert_eq!(0, predict_next(&[]).unwrap());
+        assert_eq!(7, predict_next(&[7]).unwrap());
+        assert_eq!(7, predict_first(&[7]).unwrap());
+        assert!(predict_next(&[isize::MIN, isize::MAX]).is_err());
+    }
+}
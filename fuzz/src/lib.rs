@@ -0,0 +1,153 @@
+//! Entry points shared by the fuzz targets and by the corpus replay test in
+//! the `aoc` crate. Each one feeds arbitrary input to a parser, and to the
+//! solvers where parsing succeeds. Errors are fine, any panic is a bug.
+
+use std::str;
+
+use aoc_common::{DynSolution, Part, Solver};
+
+pub type Target = fn(&[u8]);
+
+/// Every target by name, matching the directories in `fuzz/corpus`.
+pub const TARGETS: &[(&str, Target)] = &[
+    ("solve", solve),
+    ("schematic", schematic),
+    ("game", game),
+    ("card", card),
+    ("almanac", almanac),
+    ("category_map", category_map),
+    ("races", races),
+    ("hand", hand),
+    ("network", network),
+    ("histories", histories),
+    ("constraints", constraints),
+    ("cubes", cubes),
+    ("lexicon", lexicon),
+];
+
+static SOLUTIONS: &[&dyn DynSolution] = &[
+    &Solver::<aoc01::Day01>::new(),
+    &Solver::<aoc02::Day02>::new(),
+    &Solver::<aoc03::Day03>::new(),
+    &Solver::<aoc04::Day04>::new(),
+    &Solver::<aoc05::Day05>::new(),
+    &Solver::<aoc06::Day06>::new(),
+    &Solver::<aoc07::Day07>::new(),
+    &Solver::<aoc08::Day08>::new(),
+    &Solver::<aoc09::Day09>::new(),
+];
+
+/// Parses the input as every day's puzzle and solves whatever parses.
+pub fn solve(data: &[u8]) {
+    let Ok(input) = str::from_utf8(data) else {
+        return;
+    };
+    for solution in SOLUTIONS {
+        if let Ok(parsed) = solution.parse(input) {
+            for part in Part::ALL {
+                let _ = parsed.solve(part);
+            }
+        }
+    }
+}
+
+pub fn schematic(data: &[u8]) {
+    if let Ok(s) = str::from_utf8(data) {
+        let _ = aoc03::validate_schematic(s);
+        let _ = aoc03::sum_part_numbers_1(s);
+    }
+}
+
+pub fn game(data: &[u8]) {
+    if let Ok(s) = str::from_utf8(data) {
+        let _ = s.parse::<aoc02::Game>();
+    }
+}
+
+pub fn card(data: &[u8]) {
+    if let Ok(s) = str::from_utf8(data) {
+        let _ = s.parse::<aoc04::Card>();
+    }
+}
+
+pub fn almanac(data: &[u8]) {
+    if let Ok(s) = str::from_utf8(data) {
+        if let Ok(almanac) = s.parse::<aoc05::Almanac>() {
+            let _ = aoc05::find_lowest_location_1(&almanac);
+        }
+    }
+}
+
+pub fn category_map(data: &[u8]) {
+    if let Ok(s) = str::from_utf8(data) {
+        let _ = s.parse::<aoc05::CategoryMap>();
+    }
+}
+
+pub fn races(data: &[u8]) {
+    if let Ok(s) = str::from_utf8(data) {
+        if let Ok(races) = s.parse::<aoc06::Races>() {
+            let _ = aoc06::product_of_ways_to_win_1(&races);
+            let _ = aoc06::count_ways_to_win_2(&races);
+        }
+    }
+}
+
+pub fn hand(data: &[u8]) {
+    if let Ok(s) = str::from_utf8(data) {
+        let _ = s.parse::<aoc07::Hand>();
+    }
+}
+
+pub fn network(data: &[u8]) {
+    if let Ok(s) = str::from_utf8(data) {
+        if let Ok(network) = s.parse::<aoc08::Network>() {
+            let _ = aoc08::steps_until_zzz(&network);
+        }
+    }
+}
+
+pub fn histories(data: &[u8]) {
+    if let Ok(s) = str::from_utf8(data) {
+        if let Ok(histories) = aoc09::parse(s) {
+            for history in &histories {
+                let _ = aoc09::predict_next(history);
+                let _ = aoc09::predict_first(history);
+            }
+        }
+    }
+}
+
+/// Parses a lexicon and finds its words in the input itself.
+pub fn lexicon(data: &[u8]) {
+    if let Ok(s) = str::from_utf8(data) {
+        if let Ok(lexicon) = s.parse::<aoc01::lexicon::DigitLexicon>() {
+            let _ = aoc01::sum_calibration_values_2(s, &lexicon);
+        }
+    }
+}
+
+/// Parses a bag, checks that it prints back to the same bag and checks a
+/// game against it.
+pub fn cubes(data: &[u8]) {
+    if let Ok(s) = str::from_utf8(data) {
+        if let Ok(bag) = s.parse::<aoc02::Cubes>() {
+            assert_eq!(Ok(&bag), bag.to_string().parse().as_ref());
+            let _ = bag.power();
+            let game = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
+            let _ = aoc02::sum_valid_games(&[game.parse().unwrap()], &bag);
+            let _ = aoc02::report::report(&[game.parse().unwrap()], &bag);
+        }
+    }
+}
+
+/// Parses constraints and checks that they print back to the same set.
+pub fn constraints(data: &[u8]) {
+    if let Ok(s) = str::from_utf8(data) {
+        if let Ok(constraints) = s.parse::<aoc02::constraints::ConstraintSet>() {
+            assert_eq!(Ok(&constraints), constraints.to_string().parse().as_ref());
+            let game = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
+            let _ = constraints.allows(&game.parse().unwrap());
+        }
+    }
+}